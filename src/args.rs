@@ -1,4 +1,5 @@
 use clap::Parser;
+use py_checked_exceptions::{ExceptionContracts, Settings};
 use ruff_db::system::SystemPathBuf;
 use ty_project::metadata::{
     Options,
@@ -6,7 +7,7 @@ use ty_project::metadata::{
     value::{RangedValue, RelativeGlobPattern, RelativePathBuf},
 };
 
-use crate::logging::Verbosity;
+use crate::{config::ConfigOptions, logging::Verbosity};
 
 #[derive(Debug, Parser)]
 #[command(
@@ -48,6 +49,82 @@ pub(crate) struct CheckCommand {
     #[arg(long, value_name = "FILTER")]
     pub(crate) target_exceptions: Vec<String>,
 
+    /// The docstring style to prefer when a docstring's style is ambiguous or can't be detected.
+    #[arg(long, value_name = "STYLE")]
+    pub(crate) docstring_style: Option<DocstringStyle>,
+
+    /// Treat a raised exception as documented if one of its superclasses is documented.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_missing_value = "true",
+        num_args = 0..1
+    )]
+    pub(crate) allow_documented_superclasses: Option<bool>,
+
+    /// Include the exceptions raised by overriding methods of subclasses and methods of protocol
+    /// implementations in method calls.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_missing_value = "true",
+        num_args = 0..1
+    )]
+    pub(crate) virtual_dispatch: Option<bool>,
+
+    /// Include the exceptions the bundled knowledge base lists for standard library callables
+    /// and builtin operations.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_missing_value = "true",
+        num_args = 0..1
+    )]
+    pub(crate) stdlib_exceptions: Option<bool>,
+
+    /// Check functions against the exceptions declared by `@raises(...)` decorators and
+    /// `Raises[...]` metadata of `Annotated` return types instead of their docstrings.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_missing_value = "true",
+        num_args = 0..1
+    )]
+    pub(crate) exception_declarations: Option<bool>,
+
+    /// Report `except` clauses that never run because earlier clauses catch all of their
+    /// exceptions.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_missing_value = "true",
+        num_args = 0..1
+    )]
+    pub(crate) unreachable_except_handlers: Option<bool>,
+
+    /// Report new exceptions raised in `except` clauses without `from exc` or `from None`.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_missing_value = "true",
+        num_args = 0..1
+    )]
+    pub(crate) reraise_loses_context: Option<bool>,
+
+    /// Report `except` clauses catching target exceptions their `try` bodies never raise.
+    #[arg(
+        long,
+        value_name = "BOOL",
+        default_missing_value = "true",
+        num_args = 0..1
+    )]
+    pub(crate) dead_except_handlers: Option<bool>,
+
+    /// Path to a TOML or JSON file declaring the exceptions raised by callables, used instead of
+    /// analyzing their definitions.
+    #[arg(long, value_name = "PATH")]
+    pub(crate) contracts: Option<SystemPathBuf>,
+
     /// Path to the Python environment.
     ///
     /// py-checked-exceptions uses the Python environment to resolve type information and third-party dependencies.
@@ -142,6 +219,49 @@ impl CheckCommand {
             ..Options::default()
         }
     }
+
+    pub(crate) fn settings(
+        &self,
+        config: &ConfigOptions,
+        contracts: ExceptionContracts,
+    ) -> Settings {
+        Settings {
+            docstring_style: self
+                .docstring_style
+                .or(config.docstring_style)
+                .unwrap_or_default()
+                .into(),
+            allow_documented_superclasses: self
+                .allow_documented_superclasses
+                .or(config.allow_documented_superclasses)
+                .unwrap_or_default(),
+            virtual_dispatch: self
+                .virtual_dispatch
+                .or(config.virtual_dispatch)
+                .unwrap_or_default(),
+            stdlib_exceptions: self
+                .stdlib_exceptions
+                .or(config.stdlib_exceptions)
+                .unwrap_or_default(),
+            exception_declarations: self
+                .exception_declarations
+                .or(config.exception_declarations)
+                .unwrap_or_default(),
+            unreachable_except_handlers: self
+                .unreachable_except_handlers
+                .or(config.unreachable_except_handlers)
+                .unwrap_or_default(),
+            reraise_loses_context: self
+                .reraise_loses_context
+                .or(config.reraise_loses_context)
+                .unwrap_or_default(),
+            dead_except_handlers: self
+                .dead_except_handlers
+                .or(config.dead_except_handlers)
+                .unwrap_or_default(),
+            contracts,
+        }
+    }
 }
 
 /// The diagnostic output format.
@@ -173,6 +293,44 @@ impl From<OutputFormat> for ty_project::metadata::options::OutputFormat {
     }
 }
 
+/// The docstring format exceptions are documented in.
+#[derive(
+    Copy,
+    Clone,
+    Hash,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    clap::ValueEnum,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum DocstringStyle {
+    /// `Raises:` sections with indented `Name: description` entries \[default\].
+    #[default]
+    Google,
+    /// `Raises` sections underlined with dashes.
+    Numpy,
+    /// reStructuredText `:raises Name:` fields.
+    Sphinx,
+    /// `@raise Name:` fields.
+    Epytext,
+}
+
+impl From<DocstringStyle> for py_checked_exceptions::DocstringStyle {
+    fn from(style: DocstringStyle) -> py_checked_exceptions::DocstringStyle {
+        match style {
+            DocstringStyle::Google => Self::Google,
+            DocstringStyle::Numpy => Self::Numpy,
+            DocstringStyle::Sphinx => Self::Sphinx,
+            DocstringStyle::Epytext => Self::Epytext,
+        }
+    }
+}
+
 /// Control when colored output is used.
 #[derive(Copy, Clone, Hash, Debug, PartialEq, Eq, PartialOrd, Ord, Default, clap::ValueEnum)]
 pub(crate) enum TerminalColor {
@@ -0,0 +1,50 @@
+use anyhow::{Context, Result};
+use ruff_db::system::{System, SystemPath};
+use serde::Deserialize;
+
+use crate::args::DocstringStyle;
+
+/// Options read from the `[tool.py-checked-exceptions]` table of the project's `pyproject.toml`.
+///
+/// Command line arguments take precedence over these options.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub(crate) struct ConfigOptions {
+    pub(crate) docstring_style: Option<DocstringStyle>,
+    pub(crate) allow_documented_superclasses: Option<bool>,
+    pub(crate) virtual_dispatch: Option<bool>,
+    pub(crate) stdlib_exceptions: Option<bool>,
+    pub(crate) exception_declarations: Option<bool>,
+    pub(crate) unreachable_except_handlers: Option<bool>,
+    pub(crate) reraise_loses_context: Option<bool>,
+    pub(crate) dead_except_handlers: Option<bool>,
+    /// Path to the exception contracts file, relative to the project root.
+    pub(crate) contracts: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PyProject {
+    tool: Option<Tool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Tool {
+    #[serde(rename = "py-checked-exceptions")]
+    py_checked_exceptions: Option<ConfigOptions>,
+}
+
+impl ConfigOptions {
+    /// Reads the options from the `pyproject.toml` in `project_root`, if there is one.
+    pub(crate) fn discover(project_root: &SystemPath, system: &dyn System) -> Result<Self> {
+        let path = project_root.join("pyproject.toml");
+        let Ok(content) = system.read_to_string(&path) else {
+            return Ok(Self::default());
+        };
+        let pyproject: PyProject =
+            toml::from_str(&content).with_context(|| format!("Failed to parse `{path}`"))?;
+        Ok(pyproject
+            .tool
+            .and_then(|tool| tool.py_checked_exceptions)
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use anyhow::{Result, bail};
+use itertools::Itertools;
+use ruff_db::{files::File, parsed::parsed_module};
+use ruff_text_size::{Ranged, TextRange};
+use serde::Deserialize;
+use ty_project::Db;
+use ty_python_semantic::{ResolvedDefinition, semantic_index::definition::Definition};
+
+use crate::transitive_error::exception::Exception;
+use crate::transitive_error::extract::{resolve_qualified_exception_name, resolve_qualified_name};
+
+/// The format of an exception contracts file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractFormat {
+    Toml,
+    Json,
+}
+
+impl ContractFormat {
+    /// Picks the format from a file extension, defaulting to TOML.
+    pub fn from_extension(extension: Option<&str>) -> Self {
+        match extension {
+            Some("json") => Self::Json,
+            _ => Self::Toml,
+        }
+    }
+}
+
+/// Exceptions raised by callables, declared by the project instead of inferred from their code.
+///
+/// Calls to a callable with a contract report the declared exceptions and aren't descended into,
+/// which covers third-party libraries that are too dynamic to analyze or compiled.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExceptionContracts {
+    /// Qualified names of the exceptions raised by each callable, keyed by its qualified name.
+    #[serde(default)]
+    raises: BTreeMap<String, Vec<String>>,
+}
+
+impl ExceptionContracts {
+    pub fn parse(content: &str, format: ContractFormat) -> Result<Self> {
+        Ok(match format {
+            ContractFormat::Toml => toml::from_str(content)?,
+            ContractFormat::Json => serde_json::from_str(content)?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raises.is_empty()
+    }
+
+    /// Fails if a declared exception name doesn't resolve to an exception class, which is usually
+    /// a typo that would otherwise silently drop the exception from the contract.
+    pub fn validate(&self, db: &dyn Db) -> Result<()> {
+        let unresolved = self
+            .raises
+            .values()
+            .flatten()
+            .filter(|name| resolve_qualified_exception_name(db, name).is_none())
+            .map(String::as_str)
+            .unique()
+            .collect::<Vec<_>>();
+        if !unresolved.is_empty() {
+            bail!(
+                "The exception contracts list names that don't resolve to exception classes: {}",
+                unresolved.join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the exceptions declared for the callable defined by `definition`.
+    ///
+    /// A contract applies if its key is `qualified_name`, or if it resolves to `definition`
+    /// through re-exports, e.g. `requests.get` for the `get` defined in `requests.api`.
+    pub(crate) fn declared_exceptions(
+        self: &Arc<Self>,
+        db: &dyn Db,
+        definition: Definition,
+        qualified_name: Option<&str>,
+    ) -> Option<Vec<Exception>> {
+        let resolved = resolved_contracts(db, Arc::clone(self));
+        if let Some(exceptions) = qualified_name.and_then(|name| resolved.by_name.get(name)) {
+            return Some(exceptions.clone());
+        }
+        resolved
+            .by_definition
+            .get(&callable_key(db, definition))
+            .cloned()
+    }
+}
+
+/// A callable, identified by its file and the range of its definition.
+type CallableKey = (File, TextRange);
+
+/// Exception contracts with their names resolved, so calls don't resolve them again.
+#[derive(Debug, Default, get_size2::GetSize)]
+struct ResolvedContracts {
+    /// The exceptions declared for each contract key.
+    by_name: BTreeMap<String, Vec<Exception>>,
+    /// The exceptions declared for each callable a contract key resolves to.
+    by_definition: HashMap<CallableKey, Vec<Exception>>,
+}
+
+#[salsa::tracked(returns(ref), no_eq, heap_size=ruff_memory_usage::heap_size)]
+fn resolved_contracts(db: &dyn Db, contracts: Arc<ExceptionContracts>) -> ResolvedContracts {
+    let mut resolved = ResolvedContracts::default();
+    for (callable, names) in &contracts.raises {
+        let exceptions = names
+            .iter()
+            .filter_map(|name| resolve_qualified_exception_name(db, name))
+            .collect::<Vec<_>>();
+        for definition in resolve_qualified_name(db, callable).into_iter().flatten() {
+            if let ResolvedDefinition::Definition(definition) = definition {
+                resolved
+                    .by_definition
+                    .entry(callable_key(db, definition))
+                    .or_insert_with(|| exceptions.clone());
+            }
+        }
+        resolved.by_name.insert(callable.clone(), exceptions);
+    }
+    resolved
+}
+
+fn callable_key(db: &dyn Db, definition: Definition) -> CallableKey {
+    let file = definition.file(db);
+    let module = parsed_module(db, file).load(db);
+    (file, definition.full_range(db, &module).range())
+}
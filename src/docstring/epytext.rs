@@ -0,0 +1,28 @@
+use super::fields::FieldList;
+use super::model::{Docstring, RaisesSection};
+
+const FIELDS: FieldList = FieldList {
+    prefix: '@',
+    // Field names Epytext accepts for documenting exceptions.
+    raises_fields: &["raise", "raises"],
+    // Field names recognized as Epytext fields.
+    field_names: &[
+        "cvar", "ivar", "keyword", "note", "param", "raise", "raises", "return", "returns",
+        "rtype", "type", "var",
+    ],
+};
+
+pub(super) fn matches(docstring: &Docstring) -> bool {
+    FIELDS.matches(docstring)
+}
+
+/// Parses Epytext `@raise X:` fields, e.g.
+///
+/// ```text
+/// @param key: The key to look up.
+/// @raise KeyError, IndexError: If the key is missing or
+///     out of range.
+/// ```
+pub(super) fn parse_raises<'a>(docstring: &Docstring<'a>) -> Option<RaisesSection<'a>> {
+    FIELDS.parse_raises(docstring)
+}
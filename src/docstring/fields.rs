@@ -0,0 +1,71 @@
+use super::model::{Docstring, DocstringLine, RaisesEntry, RaisesSection};
+
+/// A docstring format documenting items in `<prefix>keyword ...: description` fields, like
+/// reStructuredText's `:raises X:` and Epytext's `@raise X:`.
+pub(super) struct FieldList {
+    /// The character each field starts with.
+    pub(super) prefix: char,
+    /// Field names the format accepts for documenting exceptions.
+    pub(super) raises_fields: &'static [&'static str],
+    /// Field names recognized as fields of the format.
+    pub(super) field_names: &'static [&'static str],
+}
+
+impl FieldList {
+    pub(super) fn matches(&self, docstring: &Docstring) -> bool {
+        docstring.lines.iter().any(|line| {
+            self.field_keyword(line)
+                .is_some_and(|keyword| self.field_names.contains(&keyword))
+        })
+    }
+
+    /// Returns the keyword of a field, e.g. `param` for `:param x: The x.`.
+    fn field_keyword<'a>(&self, line: &DocstringLine<'a>) -> Option<&'a str> {
+        if line.is_code {
+            return None;
+        }
+        let field = line.text.trim_start().strip_prefix(self.prefix)?;
+        let (field_name, _) = field.split_once(':')?;
+        field_name.split_whitespace().next()
+    }
+
+    /// Parses the fields documenting exceptions, along with the indented lines continuing their
+    /// descriptions.
+    pub(super) fn parse_raises<'a>(&self, docstring: &Docstring<'a>) -> Option<RaisesSection<'a>> {
+        let mut entries: Vec<RaisesEntry> = vec![];
+        // The indentation of the field the last entry was parsed from.
+        let mut entry_indent = None;
+        for line in docstring.lines.iter().filter(|line| !line.is_code) {
+            if let Some(indent) = entry_indent
+                && !line.is_blank()
+                && line.indent() > indent
+                && self.field_keyword(line).is_none()
+                && let Some(entry) = entries.last_mut()
+            {
+                entry.extend_description(line);
+                continue;
+            }
+            entry_indent = None;
+            let Some(field) = line.text.trim_start().strip_prefix(self.prefix) else {
+                continue;
+            };
+            let Some((field_name, description)) = field.split_once(':') else {
+                continue;
+            };
+            let Some((keyword, names)) = field_name.split_once(char::is_whitespace) else {
+                continue;
+            };
+            if !self.raises_fields.contains(&keyword) {
+                continue;
+            }
+            if let Some(entry) = RaisesEntry::parse(line, names, description) {
+                entries.push(entry);
+                entry_indent = Some(line.indent());
+            }
+        }
+        if entries.is_empty() {
+            return None;
+        }
+        Some(RaisesSection { entries })
+    }
+}
@@ -0,0 +1,75 @@
+use itertools::Itertools;
+
+use super::model::{Docstring, RaisesEntry, RaisesSection};
+
+/// Section names recognized as the start of a Google-style section.
+const SECTION_NAMES: [&str; 22] = [
+    "Args",
+    "Arguments",
+    "Attributes",
+    "Example",
+    "Examples",
+    "Keyword Args",
+    "Keyword Arguments",
+    "Methods",
+    "Note",
+    "Notes",
+    "Other Parameters",
+    "Parameters",
+    "Raises",
+    "References",
+    "Return",
+    "Returns",
+    "See Also",
+    "Todo",
+    "Warning",
+    "Warnings",
+    "Yield",
+    "Yields",
+];
+
+pub(super) fn matches(docstring: &Docstring) -> bool {
+    docstring.lines.iter().any(|l| {
+        !l.is_code
+            && l.content()
+                .strip_suffix(':')
+                .is_some_and(|name| SECTION_NAMES.contains(&name))
+    })
+}
+
+/// Parses a Google-style `Raises:` section, e.g.
+///
+/// ```text
+/// Raises:
+///     ValueError, TypeError: If the input is invalid, see
+///         https://example.com for details.
+/// ```
+pub(super) fn parse_raises<'a>(docstring: &Docstring<'a>) -> Option<RaisesSection<'a>> {
+    let lines = &docstring.lines;
+    let (start_index, section_header) = lines
+        .iter()
+        .find_position(|l| !l.is_code && l.content() == "Raises:")?;
+
+    let mut entries: Vec<RaisesEntry> = vec![];
+    let mut first_entry_indent = None;
+    for line in lines[start_index + 1..]
+        .iter()
+        .take_while(|l| !l.is_blank() && l.indent() > section_header.indent())
+    {
+        let indent = line.indent();
+        let entry_indent = *first_entry_indent.get_or_insert(indent);
+        if indent > entry_indent {
+            if let Some(entry) = entries.last_mut() {
+                entry.extend_description(line);
+            }
+            continue;
+        }
+        // Only the first colon separates the names, the description may contain more.
+        if let Some((names, description)) = line.content().split_once(':')
+            && let Some(entry) = RaisesEntry::parse(line, names, description)
+        {
+            entries.push(entry);
+        }
+    }
+    Some(RaisesSection { entries })
+}
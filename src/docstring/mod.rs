@@ -0,0 +1,335 @@
+use itertools::Itertools;
+use ruff_db::{
+    diagnostic::{Annotation, Diagnostic, DiagnosticId, LintName, Severity, Span},
+    files::{File, FileRange},
+    source::source_text,
+};
+use ruff_linter::docstrings::extraction::docstring_from;
+use ruff_python_ast::StmtFunctionDef;
+use ruff_text_size::{Ranged, TextRange};
+use ty_project::Db;
+
+use crate::Exception;
+use crate::docstring::model::{Docstring, RaisesSection};
+use crate::settings::Settings;
+use crate::transitive_error::declaration::DeclaredException;
+use crate::transitive_error::extract::resolve_exception_name;
+use crate::transitive_error::raise::FunctionRaise;
+
+pub(crate) use model::split_names;
+pub(crate) use overrides::compare_override_exceptions;
+
+mod epytext;
+mod fields;
+mod google;
+mod model;
+mod numpy;
+mod overrides;
+mod sphinx;
+
+/// A docstring format that exceptions can be documented in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DocstringStyle {
+    #[default]
+    Google,
+    Numpy,
+    Sphinx,
+    Epytext,
+}
+
+impl DocstringStyle {
+    const ALL: [DocstringStyle; 4] = [
+        DocstringStyle::Google,
+        DocstringStyle::Numpy,
+        DocstringStyle::Sphinx,
+        DocstringStyle::Epytext,
+    ];
+
+    /// Returns the styles used in the docstring, with `preferred` first if it is one of them.
+    ///
+    /// Falls back to `preferred` when the style can't be detected.
+    fn detect(docstring: &Docstring, preferred: DocstringStyle) -> Vec<DocstringStyle> {
+        let detected = Self::ALL
+            .into_iter()
+            .filter(|style| style.matches(docstring))
+            .sorted_by_key(|style| *style != preferred)
+            .collect_vec();
+        if detected.is_empty() {
+            vec![preferred]
+        } else {
+            detected
+        }
+    }
+
+    fn matches(self, docstring: &Docstring) -> bool {
+        match self {
+            DocstringStyle::Google => google::matches(docstring),
+            DocstringStyle::Numpy => numpy::matches(docstring),
+            DocstringStyle::Sphinx => sphinx::matches(docstring),
+            DocstringStyle::Epytext => epytext::matches(docstring),
+        }
+    }
+
+    fn parse_raises<'a>(self, docstring: &Docstring<'a>) -> Option<RaisesSection<'a>> {
+        match self {
+            DocstringStyle::Google => google::parse_raises(docstring),
+            DocstringStyle::Numpy => numpy::parse_raises(docstring),
+            DocstringStyle::Sphinx => sphinx::parse_raises(docstring),
+            DocstringStyle::Epytext => epytext::parse_raises(docstring),
+        }
+    }
+}
+
+impl std::fmt::Display for DocstringStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocstringStyle::Google => f.write_str("Google"),
+            DocstringStyle::Numpy => f.write_str("NumPy"),
+            DocstringStyle::Sphinx => f.write_str("Sphinx"),
+            DocstringStyle::Epytext => f.write_str("Epytext"),
+        }
+    }
+}
+
+/// Returns the range of the docstring of `func` and the exceptions its `Raises` section
+/// documents, skipping names that don't resolve to exception classes.
+///
+/// Returns `None` if there's no docstring or it has no `Raises` section.
+pub(crate) fn documented_exceptions(
+    db: &dyn Db,
+    file: File,
+    func: &StmtFunctionDef,
+    settings: &Settings,
+) -> Option<(TextRange, Vec<Exception>)> {
+    let docstring = docstring_from(&func.body)?;
+    let source = source_text(db, file);
+    let parsed = Docstring::from_literal(source.as_str(), docstring);
+    let styles = DocstringStyle::detect(&parsed, settings.docstring_style);
+    let section = documented_errors(db, file, func, &parsed, &styles)?;
+    let exceptions = section
+        .into_iter()
+        .filter_map(|documented| documented.exception)
+        .collect();
+    Some((docstring.range(), exceptions))
+}
+
+pub fn compare_documented_exceptions(
+    db: &dyn Db,
+    file: File,
+    func: &StmtFunctionDef,
+    errors: &[FunctionRaise],
+    settings: &Settings,
+) -> Vec<Diagnostic> {
+    let Some(docstring) = docstring_from(&func.body) else {
+        return errors.iter().map(|e| e.into()).collect();
+    };
+    let source = source_text(db, file);
+    let parsed = Docstring::from_literal(source.as_str(), docstring);
+
+    let mut diagnostics = vec![];
+    let styles = DocstringStyle::detect(&parsed, settings.docstring_style);
+    if styles.len() > 1 {
+        let mut diagnostic = Diagnostic::new(
+            DiagnosticId::Lint(LintName::of("mixed-docstring-styles")),
+            Severity::Warning,
+            format!(
+                "Docstring mixes {} styles",
+                styles.iter().sorted().join(" and ")
+            ),
+        );
+        diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(
+            file,
+            docstring.range(),
+        ))));
+        diagnostics.push(diagnostic);
+    }
+
+    let Some(section) = documented_errors(db, file, func, &parsed, &styles) else {
+        diagnostics.extend(errors.iter().map(Diagnostic::from));
+        return diagnostics;
+    };
+
+    let mut documented_errors = vec![];
+    for documented in section {
+        match documented.exception {
+            Some(exception) => documented_errors.push(DocumentedError {
+                exception,
+                name: documented.name,
+                range: documented.range,
+                description_range: documented.description_range,
+            }),
+            None => diagnostics.push(unresolved_error_diagnostic(
+                file,
+                "Documented",
+                documented.name,
+                documented.range,
+            )),
+        }
+    }
+    diagnostics.extend(compare_exceptions(
+        file,
+        &documented_errors,
+        errors,
+        settings,
+    ));
+    diagnostics
+}
+
+/// An exception name documented in a docstring, along with the exception class it resolves to.
+struct ResolvedName<'a> {
+    exception: Option<Exception>,
+    name: &'a str,
+    range: TextRange,
+    description_range: Option<TextRange>,
+}
+
+/// Parses the `Raises` section of the docstring of `func` in the first of `styles` that has one,
+/// resolving the documented names in the scope of `func`.
+///
+/// Returns `None` if the docstring has no `Raises` section.
+fn documented_errors<'a>(
+    db: &dyn Db,
+    file: File,
+    func: &StmtFunctionDef,
+    docstring: &Docstring<'a>,
+    styles: &[DocstringStyle],
+) -> Option<Vec<ResolvedName<'a>>> {
+    let section = styles
+        .iter()
+        .find_map(|style| style.parse_raises(docstring))?;
+    Some(
+        section
+            .entries
+            .iter()
+            .flat_map(|entry| {
+                entry.names.iter().map(|documented| ResolvedName {
+                    exception: resolve_exception_name(db, file, Some(func), documented.name),
+                    name: documented.name,
+                    range: documented.range,
+                    description_range: entry.description_range,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Compares the errors raised by a function with the exceptions it declares in code, like
+/// [`compare_documented_exceptions`] does with its docstring.
+pub(crate) fn compare_declared_exceptions(
+    db: &dyn Db,
+    file: File,
+    declared: &[DeclaredException],
+    errors: &[FunctionRaise],
+    settings: &Settings,
+) -> Vec<Diagnostic> {
+    let source = source_text(db, file);
+    let mut diagnostics = vec![];
+    let mut documented_errors = vec![];
+    for declaration in declared {
+        let name = &source[declaration.expr.range()];
+        match &declaration.exception {
+            Some(exception) => documented_errors.push(DocumentedError {
+                exception: exception.clone(),
+                name,
+                range: declaration.expr.range(),
+                description_range: None,
+            }),
+            None => diagnostics.push(unresolved_error_diagnostic(
+                file,
+                "Declared",
+                name,
+                declaration.expr.range(),
+            )),
+        }
+    }
+    diagnostics.extend(compare_exceptions(
+        file,
+        &documented_errors,
+        errors,
+        settings,
+    ));
+    diagnostics
+}
+
+/// An exception documented in a docstring or declared in code.
+struct DocumentedError<'a> {
+    exception: Exception,
+    name: &'a str,
+    range: TextRange,
+    description_range: Option<TextRange>,
+}
+
+fn unresolved_error_diagnostic(
+    file: File,
+    source: &str,
+    name: &str,
+    range: TextRange,
+) -> Diagnostic {
+    let mut diagnostic = Diagnostic::new(
+        DiagnosticId::Lint(LintName::of("unresolved-documented-error")),
+        Severity::Error,
+        format!("{source} error {name} does not resolve to an exception class"),
+    );
+    diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(file, range))));
+    diagnostic
+}
+
+/// Reports the raised errors that aren't documented, followed by the documented errors that
+/// aren't raised.
+fn compare_exceptions(
+    file: File,
+    documented_errors: &[DocumentedError],
+    errors: &[FunctionRaise],
+    settings: &Settings,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let is_documented_by = |raised: &Exception, documented: &Exception| {
+        if settings.allow_documented_superclasses {
+            raised.is_subclass_of(documented)
+        } else {
+            // A group documented without its contents documents any group of its class.
+            raised.qualified_name == documented.qualified_name
+                && (documented.contained.is_empty()
+                    || raised.contained.iter().all(|contained| {
+                        documented
+                            .contained
+                            .iter()
+                            .any(|d| d.qualified_name == contained.qualified_name)
+                    }))
+        }
+    };
+    let undocumented_errors = errors.iter().filter(|e| {
+        !documented_errors
+            .iter()
+            .any(|documented| is_documented_by(e.name(), &documented.exception))
+    });
+    let extra_documented_errors = documented_errors.iter().filter(|documented| {
+        !errors
+            .iter()
+            .any(|e| is_documented_by(e.name(), &documented.exception))
+    });
+
+    diagnostics.extend(undocumented_errors.map(Diagnostic::from));
+    diagnostics.extend(extra_documented_errors.map(|documented| {
+        let mut diagnostic = Diagnostic::new(
+            DiagnosticId::Lint(LintName::of("extra-documented-error")),
+            Severity::Error,
+            format!(
+                "Documents extra error that is never raised {}",
+                documented.name
+            ),
+        );
+        diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(
+            file,
+            documented.range,
+        ))));
+        if let Some(description_range) = documented.description_range {
+            diagnostic.annotate(Annotation::secondary(Span::from(FileRange::new(
+                file,
+                description_range,
+            ))));
+        }
+        diagnostic
+    }));
+    diagnostics
+}
@@ -0,0 +1,170 @@
+use ruff_python_ast::ExprStringLiteral;
+use ruff_source_file::UniversalNewlineIterator;
+use ruff_text_size::{TextRange, TextSize};
+
+/// A docstring split into lines, built from the source text of the string literal.
+///
+/// Working on the source text instead of the literal's value keeps every range exact regardless
+/// of string prefixes, the position of the opening quotes, indentation or line endings.
+#[derive(Debug)]
+pub(super) struct Docstring<'a> {
+    pub(super) lines: Vec<DocstringLine<'a>>,
+}
+
+impl<'a> Docstring<'a> {
+    pub(super) fn from_literal(source: &'a str, literal: &ExprStringLiteral) -> Self {
+        let mut lines = vec![];
+        let mut in_fenced_block = false;
+        for part in literal.value.iter() {
+            let content_range = part.content_range();
+            for line in
+                UniversalNewlineIterator::with_offset(&source[content_range], content_range.start())
+            {
+                let text = line.as_str();
+                let trimmed = text.trim_start();
+                let is_fence = trimmed.starts_with("```") || trimmed.starts_with("~~~");
+                if is_fence {
+                    in_fenced_block = !in_fenced_block;
+                }
+                let is_code = is_fence
+                    || in_fenced_block
+                    || trimmed.starts_with(">>>")
+                    || trimmed.starts_with("...");
+                lines.push(DocstringLine {
+                    offset: line.start(),
+                    text,
+                    is_code,
+                });
+            }
+        }
+        Self { lines }
+    }
+}
+
+/// A single line of a docstring, without its line ending.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct DocstringLine<'a> {
+    /// The offset of the line's first character in the file.
+    pub(super) offset: TextSize,
+    pub(super) text: &'a str,
+    /// Whether the line is part of a code example, e.g. a doctest or a fenced code block.
+    pub(super) is_code: bool,
+}
+
+impl<'a> DocstringLine<'a> {
+    /// The width of the line's indentation, with tabs expanded to multiples of eight columns.
+    pub(super) fn indent(&self) -> usize {
+        self.text
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .fold(0, |width, c| match c {
+                '\t' => width + 8 - width % 8,
+                _ => width + 1,
+            })
+    }
+
+    /// The line's text without leading and trailing whitespace.
+    pub(super) fn content(&self) -> &'a str {
+        self.text.trim()
+    }
+
+    pub(super) fn is_blank(&self) -> bool {
+        self.content().is_empty()
+    }
+
+    /// The range of `part`, which has to be a subslice of the line's text.
+    pub(super) fn range_of(&self, part: &str) -> TextRange {
+        let column = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        TextRange::at(
+            self.offset + TextSize::new(column as u32),
+            TextSize::new(part.len() as u32),
+        )
+    }
+}
+
+/// The part of a docstring that documents which exceptions a function raises.
+///
+/// For field list styles (Sphinx, Epytext) the section is made up of all raises fields.
+#[derive(Debug)]
+pub(super) struct RaisesSection<'a> {
+    pub(super) entries: Vec<RaisesEntry<'a>>,
+}
+
+/// An entry of a raises section, documenting one or more exceptions with a shared description.
+#[derive(Debug)]
+pub(super) struct RaisesEntry<'a> {
+    pub(super) names: Vec<DocumentedName<'a>>,
+    pub(super) description_range: Option<TextRange>,
+}
+
+/// A single documented exception name.
+#[derive(Debug)]
+pub(super) struct DocumentedName<'a> {
+    pub(super) name: &'a str,
+    pub(super) range: TextRange,
+}
+
+impl<'a> RaisesEntry<'a> {
+    /// Creates an entry from a comma-separated list of `names` and a `description`, which both
+    /// have to be subslices of `line`.
+    ///
+    /// Returns `None` if any of the names isn't a valid exception name.
+    pub(super) fn parse(
+        line: &DocstringLine<'a>,
+        names: &'a str,
+        description: &'a str,
+    ) -> Option<Self> {
+        let names = split_names(names)
+            .map(|name| {
+                let name = name.trim();
+                is_exception_name(name).then(|| DocumentedName {
+                    name,
+                    range: line.range_of(name),
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let description = description.trim();
+        Some(Self {
+            names,
+            description_range: (!description.is_empty()).then(|| line.range_of(description)),
+        })
+    }
+
+    /// Extends the description with a continuation line.
+    pub(super) fn extend_description(&mut self, line: &DocstringLine) {
+        let range = line.range_of(line.content());
+        self.description_range = Some(
+            self.description_range
+                .map_or(range, |description| description.cover(range)),
+        );
+    }
+}
+
+/// Splits a comma-separated list of names, ignoring the commas of exception groups, e.g.
+/// `ExceptionGroup[ValueError, KeyError]`.
+pub(crate) fn split_names(names: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0usize;
+    names.split(move |c| {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        c == ',' && depth == 0
+    })
+}
+
+/// Whether `name` is a dotted exception name, optionally followed by the exceptions a group
+/// contains, e.g. `ExceptionGroup[ValueError]`.
+fn is_exception_name(name: &str) -> bool {
+    if let Some((group, contained)) = name.split_once('[') {
+        return is_exception_name(group)
+            && contained.strip_suffix(']').is_some_and(|contained| {
+                split_names(contained).all(|e| is_exception_name(e.trim()))
+            });
+    }
+    !name.is_empty()
+        && name
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_alphanumeric() || c == '_'))
+}
@@ -0,0 +1,81 @@
+use super::model::{Docstring, DocstringLine, RaisesEntry, RaisesSection};
+
+/// Section names recognized as the start of a NumPy-style section.
+const SECTION_NAMES: [&str; 13] = [
+    "Attributes",
+    "Examples",
+    "Methods",
+    "Notes",
+    "Other Parameters",
+    "Parameters",
+    "Raises",
+    "Receives",
+    "References",
+    "Returns",
+    "See Also",
+    "Warns",
+    "Yields",
+];
+
+pub(super) fn matches(docstring: &Docstring) -> bool {
+    let lines = &docstring.lines;
+    (0..lines.len()).any(|i| {
+        SECTION_NAMES
+            .iter()
+            .any(|name| is_section_header(lines, i, name))
+    })
+}
+
+/// Parses a NumPy-style `Raises` section, e.g.
+///
+/// ```text
+/// Raises
+/// ------
+/// ValueError, TypeError
+///     If the input is invalid.
+/// ```
+pub(super) fn parse_raises<'a>(docstring: &Docstring<'a>) -> Option<RaisesSection<'a>> {
+    let lines = &docstring.lines;
+    let start_index = (0..lines.len()).find(|&i| is_section_header(lines, i, "Raises"))?;
+    let section_indent = lines[start_index].indent();
+
+    let mut entries = vec![];
+    let mut current_entry: Option<RaisesEntry> = None;
+    // Skip the header and its underline.
+    for (index, line) in lines.iter().enumerate().skip(start_index + 2) {
+        if line.is_blank() {
+            continue;
+        }
+        let indent = line.indent();
+        if indent < section_indent || is_section_header(lines, index, line.content()) {
+            break;
+        }
+        if indent > section_indent {
+            // Description of the preceding exception.
+            if let Some(entry) = &mut current_entry {
+                entry.extend_description(line);
+            }
+            continue;
+        }
+        entries.extend(current_entry.take());
+        current_entry = RaisesEntry::parse(line, line.content(), "");
+    }
+    entries.extend(current_entry);
+    Some(RaisesSection { entries })
+}
+
+/// Returns `true` if the line at `index` is `name` underlined by a line of dashes.
+fn is_section_header(lines: &[DocstringLine], index: usize, name: &str) -> bool {
+    let Some(underline) = lines.get(index + 1) else {
+        return false;
+    };
+    let header = lines[index];
+    if header.is_code {
+        return false;
+    }
+    let underline_text = underline.content();
+    header.content() == name
+        && !underline_text.is_empty()
+        && underline_text.chars().all(|c| c == '-')
+        && underline.indent() == header.indent()
+}
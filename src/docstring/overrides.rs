@@ -0,0 +1,89 @@
+use ruff_db::{
+    diagnostic::{Annotation, Diagnostic, Span},
+    files::{File, FileRange},
+    parsed::parsed_module,
+};
+use ruff_python_ast::{StmtClassDef, StmtFunctionDef};
+use ty_project::Db;
+
+use crate::docstring::documented_exceptions;
+use crate::module::ModuleCollector;
+use crate::settings::Settings;
+use crate::transitive_error::declaration::declared_exceptions;
+use crate::transitive_error::extract::{find_own_method, mro_definitions};
+use crate::transitive_error::raise::FunctionRaise;
+
+/// Constructors aren't called through a base class, so their overrides may raise new errors.
+const CONSTRUCTORS: [&str; 2] = ["__init__", "__new__"];
+
+/// Reports the errors raised by `method` of `cls` that the method it overrides doesn't document.
+///
+/// The overridden method is looked up through the method resolution order of `cls`, and is only
+/// checked against if it documents what it raises, in a `Raises` section of its docstring or,
+/// with [`Settings::exception_declarations`], in code declarations. Subclasses of documented
+/// exceptions may be raised.
+pub(crate) fn compare_override_exceptions(
+    db: &dyn Db,
+    file: File,
+    cls: &StmtClassDef,
+    method: &StmtFunctionDef,
+    errors: &[FunctionRaise],
+    settings: &Settings,
+) -> Vec<Diagnostic> {
+    if CONSTRUCTORS.contains(&method.name.as_str()) {
+        return vec![];
+    }
+    let Some((base_file, base_range)) = mro_definitions(db, file, cls)
+        .into_iter()
+        .skip(1)
+        .find_map(|base| find_own_method(db, base, method.name.as_str()))
+    else {
+        return vec![];
+    };
+    let module = parsed_module(db, base_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    let Some(base_method) = module_collector
+        .find_functions(&base_range)
+        .first()
+        .copied()
+    else {
+        return vec![];
+    };
+    let declared = settings
+        .exception_declarations
+        .then(|| declared_exceptions(db, base_file, base_method))
+        .flatten();
+    let (contract_range, documented) = match declared {
+        Some(declared) => (
+            base_method.name.range,
+            declared
+                .into_iter()
+                .filter_map(|declared| declared.exception)
+                .collect::<Vec<_>>(),
+        ),
+        None => match documented_exceptions(db, base_file, base_method, settings) {
+            Some(documented) => documented,
+            None => return vec![],
+        },
+    };
+
+    errors
+        .iter()
+        .filter(|e| !documented.iter().any(|d| e.name().is_subclass_of(d)))
+        .map(|e| {
+            let mut diagnostic = e.diagnostic(
+                "override-raises-undocumented",
+                format!(
+                    "Raises error {} not documented by the overridden method",
+                    e.name().name
+                ),
+            );
+            diagnostic.annotate(Annotation::secondary(Span::from(FileRange::new(
+                base_file,
+                contract_range,
+            ))));
+            diagnostic
+        })
+        .collect()
+}
@@ -0,0 +1,47 @@
+use super::fields::FieldList;
+use super::model::{Docstring, RaisesSection};
+
+const FIELDS: FieldList = FieldList {
+    prefix: ':',
+    // Field names Sphinx accepts for documenting exceptions.
+    raises_fields: &["raises", "raise", "except", "exception"],
+    // Field names recognized as Sphinx info fields.
+    field_names: &[
+        "arg",
+        "argument",
+        "cvar",
+        "except",
+        "exception",
+        "ivar",
+        "key",
+        "keyword",
+        "param",
+        "parameter",
+        "raise",
+        "raises",
+        "return",
+        "returns",
+        "rtype",
+        "type",
+        "var",
+        "vartype",
+        "yield",
+        "yields",
+        "ytype",
+    ],
+};
+
+pub(super) fn matches(docstring: &Docstring) -> bool {
+    FIELDS.matches(docstring)
+}
+
+/// Parses reStructuredText `:raises X:` fields, e.g.
+///
+/// ```text
+/// :param key: The key to look up.
+/// :raises KeyError, IndexError: If the key is missing or
+///     out of range.
+/// ```
+pub(super) fn parse_raises<'a>(docstring: &Docstring<'a>) -> Option<RaisesSection<'a>> {
+    FIELDS.parse_raises(docstring)
+}
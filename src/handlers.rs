@@ -0,0 +1,64 @@
+use ruff_db::{
+    diagnostic::{Annotation, Diagnostic, DiagnosticId, LintName, Severity, Span},
+    files::{File, FileRange},
+};
+use ruff_text_size::TextRange;
+
+use crate::transitive_error::exception::Exception;
+use crate::transitive_error::visitor::UnreachableHandler;
+
+/// Reports the `except` clauses that never run, because the exceptions they catch are all caught
+/// by earlier clauses of the same `try` statement.
+pub(crate) fn unreachable_handler_diagnostics(
+    file: File,
+    unreachable_handlers: Vec<UnreachableHandler>,
+) -> Vec<Diagnostic> {
+    unreachable_handlers
+        .into_iter()
+        .map(|handler| {
+            let mut diagnostic = Diagnostic::new(
+                DiagnosticId::Lint(LintName::of("unreachable-except-handler")),
+                Severity::Warning,
+                format!(
+                    "Except clause is unreachable, an earlier clause already catches {}",
+                    handler.shadowed_by.name
+                ),
+            );
+            diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(
+                file,
+                handler.range,
+            ))));
+            diagnostic.annotate(Annotation::secondary(Span::from(FileRange::new(
+                file,
+                handler.shadowing_range,
+            ))));
+            diagnostic
+        })
+        .collect()
+}
+
+/// Reports the `except` clauses that catch only target exceptions their `try` bodies never
+/// raise, e.g. after the call that raised them was removed.
+///
+/// `dead_handlers` holds the caught exceptions and type expression range of each clause.
+pub(crate) fn dead_handler_diagnostics(
+    file: File,
+    dead_handlers: Vec<(Vec<Exception>, TextRange)>,
+) -> Vec<Diagnostic> {
+    dead_handlers
+        .into_iter()
+        .map(|(caught, range)| {
+            let names = caught.iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+            let mut diagnostic = Diagnostic::new(
+                DiagnosticId::Lint(LintName::of("dead-except-handler")),
+                Severity::Warning,
+                format!(
+                    "Except clause catches {}, which the try body never raises",
+                    names.join(", ")
+                ),
+            );
+            diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(file, range))));
+            diagnostic
+        })
+        .collect()
+}
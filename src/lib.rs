@@ -1,4 +1,6 @@
 #![feature(extend_one)]
+use std::collections::HashMap;
+
 use anyhow::Result;
 use crossbeam::channel::Sender;
 use crossbeam::channel::bounded;
@@ -14,22 +16,34 @@ use ty_python_semantic::resolve_module;
 use ty_python_semantic::semantic_index::global_scope;
 use ty_python_semantic::types::resolve_definition::find_symbol_in_scope;
 
-use crate::docstring::compare_documented_exceptions;
+use crate::docstring::{
+    compare_declared_exceptions, compare_documented_exceptions, compare_override_exceptions,
+};
+use crate::handlers::{dead_handler_diagnostics, unreachable_handler_diagnostics};
 use crate::module::ModuleCollector;
-use crate::transitive_error::call_stack::CallStack;
-use crate::transitive_error::capture_stack::ExceptionCaptureStack;
-use crate::transitive_error::visitor::get_transitive_errors;
+use crate::reraise::reraise_diagnostics;
+use crate::transitive_error::declaration::{declared_exceptions, is_stub};
+use crate::transitive_error::options::AnalysisOptions;
+use crate::transitive_error::visitor::analyze_function;
 
+mod contracts;
 mod docstring;
+mod handlers;
 mod module;
+mod reraise;
+mod settings;
 mod transitive_error;
 
+pub use contracts::{ContractFormat, ExceptionContracts};
+pub use docstring::DocstringStyle;
+pub use settings::Settings;
 pub use transitive_error::exception::Exception;
 pub use transitive_error::extract::extract_exception;
 
 pub fn analyze_project(
     db: ProjectDatabase,
     target_exceptions: Vec<crate::Exception>,
+    settings: Settings,
     progress_bar: Option<&'static ProgressBar>,
 ) -> Result<impl Iterator<Item = Diagnostic>> {
     let (sender, receiver) = bounded(10);
@@ -44,10 +58,10 @@ pub fn analyze_project(
         }
 
         files.into_par_iter().for_each_with(
-            (db, target_exceptions),
-            |(db, target_exceptions), file| {
+            (db, target_exceptions, settings),
+            |(db, target_exceptions, settings), file| {
                 let db2 = db.clone();
-                analyze_file(db, &sender, file, target_exceptions);
+                analyze_file(db, &sender, file, target_exceptions, settings);
                 if let Some(pb) = &progress_bar {
                     pb.set_message(file.path(&db2).as_str().to_string());
                     pb.inc(1);
@@ -66,6 +80,7 @@ pub fn analyze_file(
     sender: &Sender<Diagnostic>,
     file: File,
     target_exceptions: &Vec<crate::Exception>,
+    settings: &Settings,
 ) {
     let module = parsed_module(db, file);
     let module_ref = module.load(db);
@@ -77,17 +92,48 @@ pub fn analyze_file(
 
     let mut module_collector = ModuleCollector::new();
     module_collector.init(&module_ref);
+    let options = AnalysisOptions::new(target_exceptions.clone(), settings);
+    let method_classes = module_collector
+        .list_classes()
+        .into_iter()
+        .flat_map(|cls| {
+            cls.body
+                .iter()
+                .filter_map(|stmt| stmt.as_function_def_stmt())
+                .map(move |method| (method.range, cls))
+        })
+        .collect::<HashMap<_, _>>();
 
     for func_def in module_collector.list_functions() {
-        let errors = get_transitive_errors(
-            db,
-            file,
-            func_def,
-            target_exceptions,
-            CallStack::new(),
-            &ExceptionCaptureStack::new(),
-        );
-        let diagnostics = compare_documented_exceptions(file, &func_def.body, &errors);
+        let analysis = analyze_function(db, file, func_def, &options);
+        let errors = analysis.errors;
+        let declared = settings
+            .exception_declarations
+            .then(|| declared_exceptions(db, file, func_def))
+            .flatten();
+        let mut diagnostics = match declared {
+            // The declarations of stubs are trusted, as there's no body to compare them with.
+            Some(_) if is_stub(db, file, func_def) => vec![],
+            Some(declared) => compare_declared_exceptions(db, file, &declared, &errors, settings),
+            None => compare_documented_exceptions(db, file, func_def, &errors, settings),
+        };
+        if settings.unreachable_except_handlers {
+            diagnostics.extend(unreachable_handler_diagnostics(
+                file,
+                analysis.unreachable_handlers,
+            ));
+        }
+        if settings.reraise_loses_context {
+            diagnostics.extend(reraise_diagnostics(file, func_def));
+        }
+        if settings.dead_except_handlers {
+            diagnostics.extend(dead_handler_diagnostics(file, analysis.dead_handlers));
+        }
+        if let Some(cls) = method_classes.get(&func_def.range) {
+            diagnostics.extend(compare_override_exceptions(
+                db, file, cls, func_def, &errors, settings,
+            ));
+        }
         for diagnostic in diagnostics {
             sender.send(diagnostic).unwrap();
         }
@@ -2,12 +2,14 @@ use anyhow::{Context, Result, anyhow};
 use clap::Parser;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use py_checked_exceptions::{analyze_project, resolve_absolute_module_path};
+use py_checked_exceptions::{
+    ContractFormat, ExceptionContracts, analyze_project, resolve_absolute_module_path,
+};
 use rayon::ThreadPoolBuilder;
 use ruff_db::{
     diagnostic::{Diagnostic, DisplayDiagnosticConfig},
     max_parallelism,
-    system::{OsSystem, SystemPath, SystemPathBuf},
+    system::{OsSystem, System, SystemPath, SystemPathBuf},
 };
 use std::sync::LazyLock;
 use std::{fmt::Write, process::ExitCode};
@@ -17,12 +19,14 @@ use ty_project::{
 
 use crate::{
     args::{CheckCommand, Cli, Command, TerminalColor},
+    config::ConfigOptions,
     logging::setup_tracing,
     printer::Printer,
 };
 use py_checked_exceptions::Exception;
 
 mod args;
+mod config;
 mod logging;
 mod printer;
 
@@ -70,6 +74,20 @@ fn check(check: CheckCommand, cwd: SystemPathBuf) -> Result<ExitCode> {
     let mut project_metadata =
         ProjectMetadata::discover(SystemPath::new(project_path.as_str()), &system)?;
     project_metadata.apply_configuration_files(&system)?;
+    let config = ConfigOptions::discover(project_metadata.root(), &system)?;
+    let contracts = check
+        .contracts
+        .as_ref()
+        .map(|path| SystemPath::absolute(path, &project_path))
+        .or_else(|| {
+            config
+                .contracts
+                .as_ref()
+                .map(|path| SystemPath::absolute(path, project_metadata.root()))
+        })
+        .map(|path| read_contracts(&path, &system))
+        .transpose()?
+        .unwrap_or_default();
     let project_options_overrides = ProjectOptionsOverrides::new(None, check.options());
     project_metadata.apply_overrides(&project_options_overrides);
     let mut db = ProjectDatabase::new(project_metadata, system.clone())?;
@@ -83,6 +101,10 @@ fn check(check: CheckCommand, cwd: SystemPathBuf) -> Result<ExitCode> {
         "{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {pos}/{len} \n({msg})",
     )?);
 
+    contracts.validate(&db)?;
+
+    let settings = check.settings(&config, contracts);
+
     // Convert string exceptions to Exception structs
     let target_exceptions: Vec<Exception> = check
         .target_exceptions
@@ -91,7 +113,7 @@ fn check(check: CheckCommand, cwd: SystemPathBuf) -> Result<ExitCode> {
         .collect();
 
     let mut diagnostics: Vec<Diagnostic> =
-        analyze_project(db.clone(), target_exceptions, Some(&PB))?.collect();
+        analyze_project(db.clone(), target_exceptions, settings, Some(&PB))?.collect();
     PB.finish_and_clear();
 
     diagnostics.sort_unstable_by_key(|diagnostic| {
@@ -145,6 +167,15 @@ fn check(check: CheckCommand, cwd: SystemPathBuf) -> Result<ExitCode> {
     }
 }
 
+/// Reads the exception contracts file at `path`, picking its format from the extension.
+fn read_contracts(path: &SystemPath, system: &dyn System) -> Result<ExceptionContracts> {
+    let content = system
+        .read_to_string(path)
+        .with_context(|| format!("Failed to read `{path}`"))?;
+    ExceptionContracts::parse(&content, ContractFormat::from_extension(path.extension()))
+        .with_context(|| format!("Failed to parse `{path}`"))
+}
+
 fn set_colored_override(color: Option<TerminalColor>) {
     let Some(color) = color else {
         return;
@@ -43,6 +43,10 @@ impl<'a> ModuleCollector<'a> {
             .find_map(|(cls_range, cls)| if cls_range == range { Some(*cls) } else { None })
     }
 
+    pub(crate) fn list_classes(&self) -> Vec<&StmtClassDef> {
+        self.classes.iter().map(|(_, cls)| *cls).collect()
+    }
+
     pub(crate) fn list_functions(&self) -> Vec<&StmtFunctionDef> {
         let mut found = vec![];
         for (_, defs) in &self.functions {
@@ -104,11 +108,4 @@ impl<'a> StatementVisitor<'a> for ModuleCollector<'a> {
     }
 }
 
-const CLS_INIT_FNS: [&str; 6] = [
-    "__init__",
-    "__new__",
-    "__enter__",
-    "__exit__",
-    "__aenter__",
-    "__aexit__",
-];
+const CLS_INIT_FNS: [&str; 2] = ["__init__", "__new__"];
@@ -0,0 +1,68 @@
+use ruff_db::{
+    diagnostic::{Annotation, Diagnostic, DiagnosticId, LintName, Severity, Span},
+    files::{File, FileRange},
+};
+use ruff_python_ast::{
+    ExceptHandler, Expr, Stmt, StmtFunctionDef,
+    statement_visitor::{StatementVisitor, walk_stmt},
+};
+
+/// Reports `raise NewError(...)` statements in the `except` clauses of `func` that don't chain
+/// the new exception with `from exc` or `from None`, which hides whether the original exception
+/// caused it.
+///
+/// Re-raising the caught exception, with `raise` or `raise exc`, keeps its context.
+pub(crate) fn reraise_diagnostics(file: File, func: &StmtFunctionDef) -> Vec<Diagnostic> {
+    let mut visitor = ReraiseVisitor {
+        file,
+        handler_names: vec![],
+        diagnostics: vec![],
+    };
+    visitor.visit_body(&func.body);
+    visitor.diagnostics
+}
+
+struct ReraiseVisitor<'a> {
+    file: File,
+    /// Names the exceptions of the enclosing `except` clauses are bound to.
+    handler_names: Vec<Option<&'a str>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> StatementVisitor<'a> for ReraiseVisitor<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            // Nested functions are checked on their own.
+            Stmt::FunctionDef(_) => {}
+            Stmt::Raise(raise) if raise.cause.is_none() && !self.handler_names.is_empty() => {
+                let Some(exc) = raise.exc.as_deref() else {
+                    return;
+                };
+                if let Expr::Name(name) = exc
+                    && self.handler_names.contains(&Some(name.id.as_str()))
+                {
+                    return;
+                }
+                let mut diagnostic = Diagnostic::new(
+                    DiagnosticId::Lint(LintName::of("reraise-loses-context")),
+                    Severity::Warning,
+                    "Raises a new exception in an except clause without `from`",
+                );
+                diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(
+                    self.file,
+                    raise.range,
+                ))));
+                self.diagnostics.push(diagnostic);
+            }
+            _ => walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_except_handler(&mut self, handler: &'a ExceptHandler) {
+        let ExceptHandler::ExceptHandler(handler) = handler;
+        self.handler_names
+            .push(handler.name.as_ref().map(|name| name.as_str()));
+        self.visit_body(&handler.body);
+        self.handler_names.pop();
+    }
+}
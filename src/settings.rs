@@ -0,0 +1,30 @@
+use crate::contracts::ExceptionContracts;
+use crate::docstring::DocstringStyle;
+
+/// Options controlling how functions are checked against their documentation.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    /// The docstring style to prefer when a docstring's style is ambiguous or can't be detected.
+    pub docstring_style: DocstringStyle,
+    /// Treat a raised exception as documented if one of its superclasses is documented.
+    pub allow_documented_superclasses: bool,
+    /// Include the exceptions raised by overriding methods of subclasses and methods of protocol
+    /// implementations in method calls.
+    pub virtual_dispatch: bool,
+    /// Include the exceptions the bundled knowledge base lists for standard library callables
+    /// and builtin operations.
+    pub stdlib_exceptions: bool,
+    /// Check functions against the exceptions declared by `@raises(...)` decorators and
+    /// `Raises[...]` metadata of `Annotated` return types instead of their docstrings, and trust
+    /// the declarations of functions whose bodies can't be analyzed.
+    pub exception_declarations: bool,
+    /// Report `except` clauses that never run because earlier clauses catch all of their
+    /// exceptions.
+    pub unreachable_except_handlers: bool,
+    /// Report new exceptions raised in `except` clauses without `from exc` or `from None`.
+    pub reraise_loses_context: bool,
+    /// Report `except` clauses catching target exceptions their `try` bodies never raise.
+    pub dead_except_handlers: bool,
+    /// Exceptions declared for callables, used instead of analyzing their definitions.
+    pub contracts: ExceptionContracts,
+}
@@ -0,0 +1,143 @@
+use ruff_db::{files::FilePath, parsed::parsed_module};
+use ruff_python_ast::{
+    Expr, Parameters, Stmt,
+    visitor::{Visitor, walk_expr, walk_stmt},
+};
+use ruff_text_size::Ranged;
+use ty_project::Db;
+use ty_python_semantic::{file_to_module, semantic_index::definition::Definition};
+
+use crate::{module::ModuleCollector, transitive_error::extract::resolve_alias};
+
+/// Standard library functions that call the callables passed to them, and how the callable is
+/// passed.
+const HIGHER_ORDER_BUILTINS: [(&str, &str, CallbackArgument<'static>); 6] = [
+    ("builtins", "map", CallbackArgument::Positional(0)),
+    ("builtins", "filter", CallbackArgument::Positional(0)),
+    ("builtins", "sorted", CallbackArgument::Keyword("key")),
+    ("builtins", "min", CallbackArgument::Keyword("key")),
+    ("builtins", "max", CallbackArgument::Keyword("key")),
+    ("functools", "reduce", CallbackArgument::Positional(0)),
+];
+
+/// How a callable is passed to a callee.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum CallbackArgument<'a> {
+    Positional(usize),
+    Keyword(&'a str),
+}
+
+/// Whether the callee defined by `definition` calls the callable it receives as `argument`.
+///
+/// User code is checked for a call of the matching parameter in the callee's body, while stubs
+/// only count for a few well-known standard library functions. `is_method_call` skips the
+/// `self`/`cls` parameter of methods called through an attribute.
+pub(crate) fn calls_callback(
+    db: &dyn Db,
+    definition: Definition,
+    argument: &CallbackArgument,
+    is_method_call: bool,
+) -> bool {
+    let definition_file = definition.file(db);
+    let module = parsed_module(db, definition_file).load(db);
+    let Some((definition_file, definition)) =
+        resolve_alias(db, &module, definition_file, definition)
+    else {
+        return false;
+    };
+    match definition_file.path(db) {
+        FilePath::System(_) => {}
+        FilePath::Vendored(_) => {
+            let Some(module) = file_to_module(db, definition_file) else {
+                return false;
+            };
+            let Some(name) = definition.name(db) else {
+                return false;
+            };
+            return HIGHER_ORDER_BUILTINS.iter().any(|(m, n, callback)| {
+                module.name(db).as_str() == *m && name == *n && callback == argument
+            });
+        }
+        FilePath::SystemVirtual(_) => return false,
+    }
+
+    let module = parsed_module(db, definition_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    let full_range = definition.full_range(db, &module);
+    module_collector
+        .find_functions(&full_range.range())
+        .iter()
+        .any(|func| {
+            parameter_name(&func.parameters, argument, is_method_call)
+                .is_some_and(|name| calls_name(&func.body, name))
+        })
+}
+
+/// Returns the name of the parameter that receives `argument`.
+fn parameter_name<'a>(
+    parameters: &'a Parameters,
+    argument: &CallbackArgument,
+    is_method_call: bool,
+) -> Option<&'a str> {
+    match argument {
+        CallbackArgument::Positional(index) => {
+            let mut positional = parameters
+                .posonlyargs
+                .iter()
+                .chain(&parameters.args)
+                .map(|p| p.parameter.name.as_str())
+                .peekable();
+            if is_method_call {
+                positional.next_if(|n| *n == "self" || *n == "cls");
+            }
+            positional
+                .nth(*index)
+                .or_else(|| parameters.vararg.as_ref().map(|p| p.name.as_str()))
+        }
+        CallbackArgument::Keyword(keyword) => parameters
+            .args
+            .iter()
+            .chain(&parameters.kwonlyargs)
+            .map(|p| p.parameter.name.as_str())
+            .find(|n| n == keyword)
+            .or_else(|| parameters.kwarg.as_ref().map(|p| p.name.as_str())),
+    }
+}
+
+/// Whether `body` calls `name`, ignoring nested functions, lambdas and classes which don't run
+/// as part of the call.
+fn calls_name(body: &[Stmt], name: &str) -> bool {
+    let mut finder = CallFinder { name, found: false };
+    finder.visit_body(body);
+    finder.found
+}
+
+struct CallFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for CallFinder<'_> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        if matches!(stmt, Stmt::FunctionDef(_) | Stmt::ClassDef(_)) {
+            return;
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        match expr {
+            Expr::Lambda(_) => return,
+            Expr::Call(call) => {
+                if let Expr::Name(name) = &*call.func
+                    && name.id.as_str() == self.name
+                {
+                    self.found = true;
+                }
+            }
+            _ => {}
+        }
+        walk_expr(self, expr);
+    }
+}
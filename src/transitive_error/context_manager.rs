@@ -0,0 +1,123 @@
+use ruff_db::{files::File, parsed::parsed_module};
+use ruff_python_ast::{Expr, ExprSubscript};
+use ruff_text_size::TextRange;
+use ty_project::Db;
+use ty_python_semantic::{
+    HasType, SemanticModel, file_to_module, semantic_index::definition::Definition,
+    types::definition::TypeDefinition,
+};
+
+use crate::{
+    module::ModuleCollector,
+    transitive_error::{
+        exception::Exception,
+        extract::{find_method, try_extract_exception_from_expr},
+    },
+};
+
+/// The methods a `with` statement calls on its context managers.
+pub(crate) struct ContextManagerProtocol {
+    pub(crate) enter: &'static str,
+    pub(crate) exit: &'static str,
+}
+
+impl ContextManagerProtocol {
+    pub(crate) fn new(is_async: bool) -> Self {
+        if is_async {
+            Self {
+                enter: "__aenter__",
+                exit: "__aexit__",
+            }
+        } else {
+            Self {
+                enter: "__enter__",
+                exit: "__exit__",
+            }
+        }
+    }
+}
+
+/// Returns the class of the context manager `expr` evaluates to.
+pub(crate) fn context_manager_class<'db>(
+    db: &'db dyn Db,
+    file: File,
+    expr: &Expr,
+) -> Option<Definition<'db>> {
+    let model = SemanticModel::new(db, file);
+    match expr.inferred_type(&model).definition(db)? {
+        TypeDefinition::Class(definition) => Some(definition),
+        _ => None,
+    }
+}
+
+/// Returns the exceptions the context manager `expr` suppresses in the body of a `with`
+/// statement.
+///
+/// `contextlib.suppress(...)` suppresses its arguments, and a context manager whose exit method
+/// is annotated to return `bool` or `Literal[True]` may suppress any exception.
+pub(crate) fn suppressed_exceptions(
+    db: &dyn Db,
+    file: File,
+    expr: &Expr,
+    class_definition: Definition,
+    protocol: &ContextManagerProtocol,
+) -> Vec<Exception> {
+    if is_contextlib_suppress(db, class_definition) {
+        let Expr::Call(call) = expr else {
+            return vec![];
+        };
+        return call
+            .arguments
+            .args
+            .iter()
+            .filter_map(|arg| try_extract_exception_from_expr(db, file, arg))
+            .collect();
+    }
+    match find_method(db, class_definition, protocol.exit) {
+        Some((method_file, method_range)) if returns_suppressing(db, method_file, method_range) => {
+            vec![Exception::base_exception()]
+        }
+        _ => vec![],
+    }
+}
+
+/// Whether the class defined by `class_definition` is `asyncio.TaskGroup`, which raises the errors
+/// of its body and tasks as an exception group.
+pub(crate) fn is_task_group(db: &dyn Db, class_definition: Definition) -> bool {
+    class_definition.name(db).as_deref() == Some("TaskGroup")
+        && file_to_module(db, class_definition.file(db))
+            .is_some_and(|module| module.name(db).as_str() == "asyncio.taskgroups")
+}
+
+fn is_contextlib_suppress(db: &dyn Db, class_definition: Definition) -> bool {
+    class_definition.name(db).as_deref() == Some("suppress")
+        && file_to_module(db, class_definition.file(db))
+            .is_some_and(|module| module.name(db).as_str() == "contextlib")
+}
+
+fn returns_suppressing(db: &dyn Db, method_file: File, method_range: TextRange) -> bool {
+    let module = parsed_module(db, method_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    module_collector
+        .find_functions(&method_range)
+        .iter()
+        .filter_map(|func| func.returns.as_deref())
+        .any(is_suppressing_annotation)
+}
+
+/// Whether a return annotation of an exit method allows it to return `True`.
+fn is_suppressing_annotation(annotation: &Expr) -> bool {
+    match annotation {
+        Expr::Name(name) => name.id.as_str() == "bool",
+        Expr::Subscript(ExprSubscript { value, slice, .. }) => {
+            let is_literal = match &**value {
+                Expr::Name(name) => name.id.as_str() == "Literal",
+                Expr::Attribute(attr) => attr.attr.as_str() == "Literal",
+                _ => false,
+            };
+            is_literal && matches!(&**slice, Expr::BooleanLiteral(literal) if literal.value)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,147 @@
+use ruff_db::files::{File, FilePath};
+use ruff_python_ast::{Expr, Stmt, StmtFunctionDef};
+use ty_project::Db;
+use ty_python_semantic::{
+    ResolvedDefinition, definitions_for_attribute, definitions_for_name, file_to_module,
+    semantic_index::definition::{Definition, DefinitionKind},
+};
+
+use crate::transitive_error::{exception::Exception, extract::try_extract_exception_from_expr};
+
+/// An exception declared in code by a `@raises(...)` decorator or `Raises[...]` metadata of an
+/// `Annotated` return type.
+pub(crate) struct DeclaredException<'a> {
+    pub(crate) expr: &'a Expr,
+    /// The exception class `expr` resolves to, if any.
+    pub(crate) exception: Option<Exception>,
+}
+
+/// Returns the exceptions `func` declares, or `None` if it has no declarations.
+///
+/// `@raises()` and `Raises[()]` declare that no exceptions are raised.
+pub(crate) fn declared_exceptions<'a>(
+    db: &dyn Db,
+    file: File,
+    func: &'a StmtFunctionDef,
+) -> Option<Vec<DeclaredException<'a>>> {
+    let decorated = func
+        .decorator_list
+        .iter()
+        .filter_map(|decorator| decorator.expression.as_call_expr())
+        .filter(|call| is_declaration_helper(db, file, &call.func, "raises"))
+        .map(|call| call.arguments.args.iter().collect::<Vec<_>>());
+    let annotated = func
+        .returns
+        .as_deref()
+        .into_iter()
+        .flat_map(annotated_metadata)
+        .filter_map(|metadata| metadata.as_subscript_expr())
+        .filter(|subscript| is_declaration_helper(db, file, &subscript.value, "Raises"))
+        .map(|subscript| match &*subscript.slice {
+            Expr::Tuple(tuple) => tuple.elts.iter().collect(),
+            expr => vec![expr],
+        });
+    let mut declarations = decorated.chain(annotated).peekable();
+    declarations.peek()?;
+    Some(
+        declarations
+            .flatten()
+            .map(|expr| DeclaredException {
+                expr,
+                exception: try_extract_exception_from_expr(db, file, expr),
+            })
+            .collect(),
+    )
+}
+
+/// Whether the body of `func` carries no information about what it raises, because it's defined
+/// in a stub file, its body is `...`, or it's an overload or abstract method without code.
+pub(crate) fn is_stub(db: &dyn Db, file: File, func: &StmtFunctionDef) -> bool {
+    if file.path(db).as_str().ends_with(".pyi") {
+        return true;
+    }
+    let is_docstring =
+        |stmt: &Stmt| matches!(stmt, Stmt::Expr(expr) if expr.value.is_string_literal_expr());
+    let is_ellipsis =
+        |stmt: &Stmt| matches!(stmt, Stmt::Expr(expr) if expr.value.is_ellipsis_literal_expr());
+    let body = match func.body.split_first() {
+        Some((first, rest)) if is_docstring(first) => rest,
+        _ => &func.body,
+    };
+    if !body.is_empty() && body.iter().all(is_ellipsis) {
+        return true;
+    }
+    let is_declaration_only = func.decorator_list.iter().any(|decorator| {
+        resolves_to(
+            db,
+            file,
+            &decorator.expression,
+            &["typing", "typing_extensions"],
+            "overload",
+        ) || resolves_to(db, file, &decorator.expression, &["abc"], "abstractmethod")
+    });
+    is_declaration_only
+        && body
+            .iter()
+            .all(|stmt| matches!(stmt, Stmt::Pass(_)) || is_ellipsis(stmt))
+}
+
+/// Returns the metadata of an `Annotated[T, ...]` annotation.
+fn annotated_metadata(annotation: &Expr) -> &[Expr] {
+    let Expr::Subscript(subscript) = annotation else {
+        return &[];
+    };
+    if !is_named(&subscript.value, "Annotated") {
+        return &[];
+    }
+    match &*subscript.slice {
+        Expr::Tuple(tuple) => tuple.elts.get(1..).unwrap_or_default(),
+        _ => &[],
+    }
+}
+
+/// Whether `expr` refers to `name`, either directly or as an attribute, e.g. `errors.raises`.
+fn is_named(expr: &Expr, name: &str) -> bool {
+    match expr {
+        Expr::Name(expr_name) => expr_name.id.as_str() == name,
+        Expr::Attribute(attr) => attr.attr.as_str() == name,
+        _ => false,
+    }
+}
+
+/// Whether `expr` refers to a declaration helper called `name`, i.e. a function or class of that
+/// name defined in the project or one of its dependencies, e.g. `errors.raises`.
+fn is_declaration_helper(db: &dyn Db, file: File, expr: &Expr, name: &str) -> bool {
+    is_named(expr, name)
+        && definitions(db, file, expr).into_iter().any(|def| {
+            matches!(def.file(db).path(db), FilePath::System(_))
+                && matches!(
+                    def.kind(db),
+                    DefinitionKind::Function(_) | DefinitionKind::Class(_)
+                )
+        })
+}
+
+/// Whether `expr` refers to the symbol `name` defined in one of the modules `modules`.
+fn resolves_to(db: &dyn Db, file: File, expr: &Expr, modules: &[&str], name: &str) -> bool {
+    is_named(expr, name)
+        && definitions(db, file, expr).into_iter().any(|def| {
+            file_to_module(db, def.file(db))
+                .is_some_and(|module| modules.contains(&module.name(db).as_str()))
+        })
+}
+
+fn definitions<'db>(db: &'db dyn Db, file: File, expr: &Expr) -> Vec<Definition<'db>> {
+    let resolved = match expr {
+        Expr::Name(name) => definitions_for_name(db, file, name),
+        Expr::Attribute(attr) => definitions_for_attribute(db, file, attr),
+        _ => vec![],
+    };
+    resolved
+        .into_iter()
+        .filter_map(|def| match def {
+            ResolvedDefinition::Definition(def) => Some(def),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,152 @@
+use ruff_db::{
+    files::{File, FilePath},
+    parsed::parsed_module,
+};
+use ruff_python_ast::{Decorator, Expr, Stmt, StmtFunctionDef};
+use ruff_text_size::{Ranged, TextRange};
+use ty_project::Db;
+use ty_python_semantic::{ResolvedDefinition, definitions_for_attribute, definitions_for_name};
+
+use crate::{
+    module::ModuleCollector,
+    transitive_error::{
+        call_stack::CallStack, capture_stack::ExceptionCaptureStack, extract::resolve_alias,
+        options::AnalysisOptions, raise::FunctionRaise, visitor::FunctionTransitiveErrorVisitor,
+    },
+};
+
+/// Combines the errors raised by `func` with the errors raised and caught by the wrappers its
+/// decorators replace it with, starting with the innermost decorator.
+///
+/// Errors of `func` that escape a wrapper keep their location, while errors raised by a wrapper
+/// itself are attributed to the decorator. Decorators that can't be resolved to a function
+/// returning a nested wrapper, e.g. ones from the standard library, leave the errors unchanged.
+pub(crate) fn apply_decorators(
+    db: &dyn Db,
+    file: File,
+    func: &StmtFunctionDef,
+    errors: Vec<FunctionRaise>,
+    options: &AnalysisOptions,
+    call_stack: &CallStack,
+    exception_capture_stack: &ExceptionCaptureStack,
+) -> Vec<FunctionRaise> {
+    func.decorator_list
+        .iter()
+        .rev()
+        .fold(errors, |errors, decorator| {
+            apply_decorator(
+                db,
+                file,
+                decorator,
+                errors,
+                options,
+                call_stack,
+                exception_capture_stack,
+            )
+        })
+}
+
+fn apply_decorator(
+    db: &dyn Db,
+    file: File,
+    decorator: &Decorator,
+    errors: Vec<FunctionRaise>,
+    options: &AnalysisOptions,
+    call_stack: &CallStack,
+    exception_capture_stack: &ExceptionCaptureStack,
+) -> Vec<FunctionRaise> {
+    let Some((decorator_file, decorator_range)) =
+        resolve_decorator(db, file, &decorator.expression)
+    else {
+        return errors;
+    };
+    let module = parsed_module(db, decorator_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    let Some(mut decorator_func) = module_collector
+        .find_functions(&decorator_range)
+        .first()
+        .copied()
+    else {
+        return errors;
+    };
+    // `@decorator(...)` calls a factory that returns the actual decorator.
+    if decorator.expression.is_call_expr() {
+        let Some(inner) = returned_function(decorator_func) else {
+            return errors;
+        };
+        decorator_func = inner;
+    }
+    let Some(wrapped_name) = decorator_func
+        .parameters
+        .posonlyargs
+        .iter()
+        .chain(&decorator_func.parameters.args)
+        .next()
+        .map(|p| p.parameter.name.as_str())
+    else {
+        return errors;
+    };
+    let Some(wrapper) = returned_function(decorator_func) else {
+        return errors;
+    };
+
+    FunctionTransitiveErrorVisitor::new(
+        db,
+        decorator_file,
+        wrapper,
+        options,
+        call_stack.clone(),
+        exception_capture_stack,
+    )
+    .wrapping(wrapped_name, &errors)
+    .transitive_errors()
+    .into_iter()
+    .map(|e| {
+        if errors.contains(&e) {
+            e
+        } else {
+            e.transitive(file, decorator.range)
+        }
+    })
+    .collect()
+}
+
+/// Resolves the function a decorator expression calls, i.e. the decorator itself for
+/// `@decorator` and the decorator factory for `@decorator(...)`.
+fn resolve_decorator(db: &dyn Db, file: File, expr: &Expr) -> Option<(File, TextRange)> {
+    let callee = match expr {
+        Expr::Call(call) => &*call.func,
+        _ => expr,
+    };
+    let defs = match callee {
+        Expr::Name(name) => definitions_for_name(db, file, name),
+        Expr::Attribute(attr) => definitions_for_attribute(db, file, attr),
+        _ => return None,
+    };
+    let def = defs.into_iter().find_map(|def| match def {
+        ResolvedDefinition::Definition(def) => Some(def),
+        _ => None,
+    })?;
+    let definition_file = def.file(db);
+    let module = parsed_module(db, definition_file).load(db);
+    let (definition_file, def) = resolve_alias(db, &module, definition_file, def)?;
+    if !matches!(definition_file.path(db), FilePath::System(_)) {
+        return None;
+    }
+    let module = parsed_module(db, definition_file).load(db);
+    Some((definition_file, def.full_range(db, &module).range()))
+}
+
+/// Returns the nested function `func` defines and returns, e.g. `wrapper` for
+/// `def wrapper(...): ...` followed by `return wrapper`.
+fn returned_function(func: &StmtFunctionDef) -> Option<&StmtFunctionDef> {
+    let returned = func.body.iter().find_map(|stmt| match stmt {
+        Stmt::Return(ret) => ret.value.as_deref()?.as_name_expr(),
+        _ => None,
+    })?;
+    func.body.iter().find_map(|stmt| {
+        stmt.as_function_def_stmt()
+            .filter(|nested| nested.name.as_str() == returned.id.as_str())
+    })
+}
@@ -0,0 +1,175 @@
+use std::collections::{HashMap, HashSet};
+
+use ruff_db::{files::File, parsed::parsed_module};
+use ruff_python_ast::Expr;
+use ruff_text_size::{Ranged, TextRange};
+use ty_project::Db;
+use ty_python_semantic::{
+    HasType, SemanticModel,
+    semantic_index::definition::Definition,
+    types::{Type, definition::TypeDefinition},
+};
+
+use crate::{
+    module::ModuleCollector,
+    transitive_error::extract::{base_class_definitions, resolve_alias},
+};
+
+/// A class, identified by its file and the range of its definition.
+type ClassKey = (File, TextRange);
+
+/// The subclasses of every class in the project that has one, and the classes defining each
+/// method name.
+#[derive(Debug, Default, get_size2::GetSize)]
+pub(crate) struct ClassHierarchy {
+    subclasses: HashMap<ClassKey, Vec<ClassKey>>,
+    /// Classes defining a method may implement protocols with it without subclassing them.
+    method_classes: HashMap<String, Vec<ClassKey>>,
+}
+
+impl ClassHierarchy {
+    /// Returns the direct and indirect subclasses of `class`.
+    fn all_subclasses(&self, class: ClassKey) -> Vec<ClassKey> {
+        let mut found = vec![];
+        let mut seen = HashSet::from([class]);
+        let mut pending = vec![class];
+        while let Some(class) = pending.pop() {
+            for subclass in self.subclasses.get(&class).into_iter().flatten() {
+                if seen.insert(*subclass) {
+                    found.push(*subclass);
+                    pending.push(*subclass);
+                }
+            }
+        }
+        found
+    }
+}
+
+#[salsa::tracked(returns(ref), no_eq, heap_size=ruff_memory_usage::heap_size)]
+pub(crate) fn class_hierarchy(db: &dyn Db) -> ClassHierarchy {
+    let mut hierarchy = ClassHierarchy::default();
+    for file in db.project().files(db).iter() {
+        let module = parsed_module(db, *file).load(db);
+        let mut module_collector = ModuleCollector::new();
+        module_collector.init(&module);
+        for cls in module_collector.list_classes() {
+            for method in cls
+                .body
+                .iter()
+                .filter_map(|stmt| stmt.as_function_def_stmt())
+            {
+                hierarchy
+                    .method_classes
+                    .entry(method.name.to_string())
+                    .or_default()
+                    .push((*file, cls.range));
+            }
+            for base in base_class_definitions(db, *file, cls) {
+                if let Some(base) = class_key(db, base) {
+                    hierarchy
+                        .subclasses
+                        .entry(base)
+                        .or_default()
+                        .push((*file, cls.range));
+                }
+            }
+        }
+    }
+    hierarchy
+}
+
+fn class_key(db: &dyn Db, definition: Definition) -> Option<ClassKey> {
+    let file = definition.file(db);
+    let module = parsed_module(db, file).load(db);
+    let (file, definition) = resolve_alias(db, &module, file, definition)?;
+    let module = parsed_module(db, file).load(db);
+    Some((file, definition.full_range(db, &module).range()))
+}
+
+/// A method of a subclass overriding a called method, or of a class implementing a protocol.
+pub(crate) struct Override {
+    pub(crate) file: File,
+    /// The range of the method's `def`.
+    pub(crate) range: TextRange,
+    /// The range of the method's name.
+    pub(crate) name_range: TextRange,
+    /// The method's name qualified by its class, e.g. `JsonHandler.process`.
+    pub(crate) qualified_name: String,
+}
+
+/// Returns the methods the method call `func`, e.g. `self.handler.process`, may dispatch to: the
+/// overriding methods in the project's subclasses of the receiver's class, or for a protocol,
+/// the methods of the project's classes implementing it, whether explicitly or structurally.
+///
+/// Only calls on instances are dispatched, calls on class objects like `Base.process(self)` call
+/// the method of the class they name.
+pub(crate) fn find_overrides(db: &dyn Db, file: File, func: &Expr) -> Vec<Override> {
+    let Expr::Attribute(attr) = func else {
+        return vec![];
+    };
+    let model = SemanticModel::new(db, file);
+    let receiver_type = attr.value.inferred_type(&model);
+    if !matches!(
+        receiver_type,
+        Type::NominalInstance(_) | Type::ProtocolInstance(_)
+    ) {
+        return vec![];
+    }
+    let Some(TypeDefinition::Class(receiver)) = receiver_type.definition(db) else {
+        return vec![];
+    };
+    let Some(receiver) = class_key(db, receiver) else {
+        return vec![];
+    };
+
+    let hierarchy = class_hierarchy(db);
+    let mut classes = hierarchy.all_subclasses(receiver);
+    if matches!(receiver_type, Type::ProtocolInstance(_)) {
+        let implementers = hierarchy
+            .method_classes
+            .get(attr.attr.as_str())
+            .into_iter()
+            .flatten()
+            .filter(|class| **class != receiver && !classes.contains(class))
+            .filter(|class| {
+                instance_type(db, **class)
+                    .is_some_and(|instance| instance.is_assignable_to(db, receiver_type))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        classes.extend(implementers);
+    }
+
+    let mut overrides = vec![];
+    for (class_file, class_range) in classes {
+        let module = parsed_module(db, class_file).load(db);
+        let mut module_collector = ModuleCollector::new();
+        module_collector.init(&module);
+        let Some(cls) = module_collector.find_class(&class_range) else {
+            continue;
+        };
+        overrides.extend(
+            cls.body
+                .iter()
+                .filter_map(|stmt| stmt.as_function_def_stmt())
+                .filter(|method| method.name.as_str() == attr.attr.as_str())
+                .map(|method| Override {
+                    file: class_file,
+                    range: method.range,
+                    name_range: method.name.range,
+                    qualified_name: format!("{}.{}", cls.name, method.name),
+                }),
+        );
+    }
+    overrides
+}
+
+/// Returns the type of the instances of the class at `key`.
+fn instance_type(db: &dyn Db, (file, range): ClassKey) -> Option<Type<'_>> {
+    let module = parsed_module(db, file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    let cls = module_collector.find_class(&range)?;
+    let model = SemanticModel::new(db, file);
+    cls.inferred_type(&model).to_instance(db)
+}
@@ -0,0 +1,127 @@
+use ruff_db::{
+    files::{File, FilePath},
+    parsed::parsed_module,
+};
+use ruff_python_ast::{CmpOp, Expr, UnaryOp};
+use ruff_text_size::TextRange;
+use ty_project::Db;
+use ty_python_semantic::{
+    HasType, ResolvedDefinition, SemanticModel, definitions_for_name, file_to_module,
+    semantic_index::definition::Definition,
+    types::{Type, definition::TypeDefinition},
+};
+
+use crate::module::ModuleCollector;
+
+/// Builtins that call a dunder method of their argument, e.g. `len(obj)` calls `obj.__len__()`.
+const BUILTIN_DUNDERS: [(&str, &str); 8] = [
+    ("len", "__len__"),
+    ("iter", "__iter__"),
+    ("next", "__next__"),
+    ("str", "__str__"),
+    ("repr", "__repr__"),
+    ("hash", "__hash__"),
+    ("bool", "__bool__"),
+    ("abs", "__abs__"),
+];
+
+/// Returns the class of the instance `expr` evaluates to.
+///
+/// Class objects and other non-instance types don't dispatch dunders to their own methods, so
+/// they're ignored.
+pub(crate) fn instance_class<'db>(
+    db: &'db dyn Db,
+    file: File,
+    expr: &Expr,
+) -> Option<Definition<'db>> {
+    let model = SemanticModel::new(db, file);
+    let ty = expr.inferred_type(&model);
+    if !matches!(ty, Type::NominalInstance(_)) {
+        return None;
+    }
+    match ty.definition(db)? {
+        TypeDefinition::Class(definition) => Some(definition),
+        _ => None,
+    }
+}
+
+/// Returns the dunder method the builtin called by `func` calls on its argument.
+pub(crate) fn builtin_dunder(db: &dyn Db, file: File, func: &Expr) -> Option<&'static str> {
+    let Expr::Name(name) = func else {
+        return None;
+    };
+    let (_, dunder) = BUILTIN_DUNDERS
+        .iter()
+        .find(|(builtin, _)| *builtin == name.id.as_str())?;
+    let is_builtin = definitions_for_name(db, file, name)
+        .into_iter()
+        .any(|def| match def {
+            ResolvedDefinition::Definition(def) => file_to_module(db, def.file(db))
+                .is_some_and(|module| module.name(db).as_str() == "builtins"),
+            _ => false,
+        });
+    is_builtin.then_some(*dunder)
+}
+
+pub(crate) fn unary_dunder(op: UnaryOp) -> Option<&'static str> {
+    match op {
+        UnaryOp::Invert => Some("__invert__"),
+        UnaryOp::UAdd => Some("__pos__"),
+        UnaryOp::USub => Some("__neg__"),
+        UnaryOp::Not => None,
+    }
+}
+
+/// Returns the dunder method a comparison calls, and whether it's called on the right operand.
+pub(crate) fn compare_dunder(op: CmpOp) -> Option<(&'static str, bool)> {
+    match op {
+        CmpOp::Eq => Some(("__eq__", false)),
+        CmpOp::NotEq => Some(("__ne__", false)),
+        CmpOp::Lt => Some(("__lt__", false)),
+        CmpOp::LtE => Some(("__le__", false)),
+        CmpOp::Gt => Some(("__gt__", false)),
+        CmpOp::GtE => Some(("__ge__", false)),
+        CmpOp::In | CmpOp::NotIn => Some(("__contains__", true)),
+        CmpOp::Is | CmpOp::IsNot => None,
+    }
+}
+
+/// Returns the class the return annotation of the method defined at `method_range` in
+/// `method_file` refers to, e.g. the iterator class returned by `__iter__`.
+pub(crate) fn returned_class<'db>(
+    db: &'db dyn Db,
+    method_file: File,
+    method_range: TextRange,
+) -> Option<Definition<'db>> {
+    let module = parsed_module(db, method_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    let returns = module_collector
+        .find_functions(&method_range)
+        .into_iter()
+        .find_map(|func| func.returns.as_deref())?;
+    let model = SemanticModel::new(db, method_file);
+    match returns.inferred_type(&model).definition(db)? {
+        TypeDefinition::Class(definition) => Some(definition),
+        _ => None,
+    }
+}
+
+/// Whether the method defined at `method_range` in `method_file` is a property getter.
+pub(crate) fn is_property(db: &dyn Db, method_file: File, method_range: TextRange) -> bool {
+    if !matches!(method_file.path(db), FilePath::System(_)) {
+        return false;
+    }
+    let module = parsed_module(db, method_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    module_collector
+        .find_functions(&method_range)
+        .iter()
+        .flat_map(|func| &func.decorator_list)
+        .any(|decorator| match &decorator.expression {
+            Expr::Name(name) => matches!(name.id.as_str(), "property" | "cached_property"),
+            Expr::Attribute(attr) => attr.attr.as_str() == "cached_property",
+            _ => false,
+        })
+}
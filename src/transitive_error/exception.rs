@@ -1,17 +1,65 @@
 #[derive(Debug, Clone, PartialEq, Eq, Hash, get_size2::GetSize)]
 pub struct Exception {
     pub name: String,
+    /// The name including the module the exception is defined in, e.g. `builtins.ValueError`.
+    pub qualified_name: String,
     pub bases: Vec<Exception>,
+    /// The exceptions an exception group contains, e.g. `ValueError` for
+    /// `ExceptionGroup[ValueError]`. Empty for other exceptions and groups of unknown contents.
+    pub contained: Vec<Exception>,
 }
 
 impl Exception {
-    pub fn new(name: String, bases: Vec<Exception>) -> Self {
-        Self { name, bases }
+    pub fn new(name: String, qualified_name: String, bases: Vec<Exception>) -> Self {
+        Self {
+            name,
+            qualified_name,
+            bases,
+            contained: vec![],
+        }
     }
 
+    /// Creates an exception group of the class `group`, e.g. `ExceptionGroup`, containing
+    /// `contained`.
+    pub fn group(group: &Exception, contained: Vec<Exception>) -> Self {
+        let class_name = group.name.split('[').next().unwrap_or_default();
+        let names = contained
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect::<Vec<_>>();
+        Self {
+            name: format!("{class_name}[{}]", names.join(", ")),
+            qualified_name: group.qualified_name.clone(),
+            bases: group.bases.clone(),
+            contained,
+        }
+    }
+
+    /// Identifies the exception including the contents of exception groups.
+    pub(crate) fn key(&self) -> String {
+        if self.contained.is_empty() {
+            return self.qualified_name.clone();
+        }
+        let contained = self
+            .contained
+            .iter()
+            .map(Exception::key)
+            .collect::<Vec<_>>();
+        format!("{}[{}]", self.qualified_name, contained.join(", "))
+    }
+
+    /// Whether `self` is `other` or one of its subclasses.
+    ///
+    /// An exception group is a subclass of a group of the same class if each of its contained
+    /// exceptions is a subclass of one contained in `other`, or if `other` doesn't specify its
+    /// contents.
     pub fn is_subclass_of(&self, other: &Exception) -> bool {
-        if self == other {
-            return true;
+        if self.qualified_name == other.qualified_name {
+            return other.contained.is_empty()
+                || self
+                    .contained
+                    .iter()
+                    .all(|e| other.contained.iter().any(|o| e.is_subclass_of(o)));
         }
 
         for base in &self.bases {
@@ -23,10 +71,33 @@ impl Exception {
         false
     }
 
+    /// Returns the part of `self` that isn't caught by `except* caught:` clauses.
+    ///
+    /// The clauses match the contained exceptions of a group, and a group whose every contained
+    /// exception matches is caught entirely. Other exceptions are caught like by `except`.
+    pub(crate) fn split_group(&self, caught: &[Exception]) -> Option<Exception> {
+        let is_caught = |e: &Exception| caught.iter().any(|c| e.is_subclass_of(c));
+        if self.contained.is_empty() {
+            return (!is_caught(self)).then(|| self.clone());
+        }
+        let remaining = self
+            .contained
+            .iter()
+            .filter(|e| !is_caught(e))
+            .cloned()
+            .collect::<Vec<_>>();
+        if remaining.is_empty() {
+            return None;
+        }
+        Some(Exception::group(self, remaining))
+    }
+
     pub fn base_exception() -> Self {
         Self {
             name: "BaseException".to_string(),
+            qualified_name: "builtins.BaseException".to_string(),
             bases: vec![],
+            contained: vec![],
         }
     }
 }
@@ -2,19 +2,36 @@ use ruff_db::{
     files::File,
     parsed::{ParsedModuleRef, parsed_module},
 };
-use ruff_python_ast::{ExceptHandler, Expr, ExprTuple};
+use ruff_python_ast::{ExceptHandler, Expr, ExprTuple, StmtClassDef, StmtFunctionDef};
 use ruff_text_size::{Ranged, TextRange};
 use ty_project::Db;
 use ty_python_semantic::{
-    ResolvedDefinition, definitions_for_attribute, definitions_for_name,
-    semantic_index::definition::{Definition, DefinitionKind},
+    HasType, ModuleName, ResolvedDefinition, SemanticModel, definitions_for_attribute,
+    definitions_for_name, file_to_module, resolve_module,
+    semantic_index::{
+        definition::{Definition, DefinitionKind},
+        global_scope,
+        scope::{NodeWithScopeRef, ScopeId, ScopeKind},
+        semantic_index,
+    },
+    types::{
+        ClassBase, Type,
+        definition::TypeDefinition,
+        resolve_definition::{ImportAliasResolution, find_symbol_in_scope, resolve_definition},
+    },
 };
 
 use crate::{
+    docstring::split_names,
     module::ModuleCollector,
     transitive_error::{
-        call_stack::CallStack, capture_stack::ExceptionCaptureStack, exception::Exception,
-        raise::FunctionRaise, visitor::get_transitive_errors,
+        call_stack::CallStack,
+        capture_stack::ExceptionCaptureStack,
+        declaration::{declared_exceptions, is_stub},
+        exception::Exception,
+        options::{AnalysisOptions, CallSite},
+        raise::FunctionRaise,
+        visitor::get_transitive_errors,
     },
 };
 
@@ -27,7 +44,7 @@ fn extract_errors_cycle_fn<'db>(
     _expr_range: TextRange,
     _definition_file: File,
     _definition: Definition<'db>,
-    _target_exceptions: Vec<Exception>,
+    _options: AnalysisOptions,
     _call_stack: CallStack,
     _exception_capture_stack: ExceptionCaptureStack,
 ) -> salsa::CycleRecoveryAction<Vec<FunctionRaise>> {
@@ -41,7 +58,7 @@ fn extract_errors_initial<'db>(
     _expr_range: TextRange,
     _definition_file: File,
     _definition: Definition<'db>,
-    _target_exceptions: Vec<Exception>,
+    _options: AnalysisOptions,
     _call_stack: CallStack,
     _exception_capture_stack: ExceptionCaptureStack,
 ) -> Vec<FunctionRaise> {
@@ -56,7 +73,7 @@ pub(crate) fn extract_errors<'db>(
     expr_range: TextRange,
     definition_file: File,
     definition: Definition<'db>,
-    target_exceptions: Vec<Exception>,
+    options: AnalysisOptions,
     call_stack: CallStack,
     exception_capture_stack: ExceptionCaptureStack,
 ) -> Vec<FunctionRaise> {
@@ -67,33 +84,192 @@ pub(crate) fn extract_errors<'db>(
         return vec![];
     };
     let module = parsed_module(db, definition_file).load(db);
-    let mut module_collector = ModuleCollector::new();
-    module_collector.init(&module);
     let full_range = definition.full_range(db, &module);
+    let call = CallSite {
+        file: expr_file,
+        range: expr_range,
+        call_stack: &call_stack,
+        exception_capture_stack: &exception_capture_stack,
+    };
+    function_errors(
+        db,
+        &call,
+        definition_file,
+        &module,
+        full_range.range(),
+        &options,
+    )
+}
+
+/// Returns the errors raised by calling the function defined at `definition_range` in
+/// `definition_file` at `call`.
+fn function_errors(
+    db: &dyn Db,
+    call: &CallSite,
+    definition_file: File,
+    module: &ParsedModuleRef,
+    definition_range: TextRange,
+    options: &AnalysisOptions,
+) -> Vec<FunctionRaise> {
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(module);
 
     let mut errors = vec![];
 
-    for func_def in module_collector.find_functions(&full_range.range()) {
-        let new_stack = call_stack.push((
+    for func_def in module_collector.find_functions(&definition_range) {
+        let key = (
             definition_file.path(db).as_str().into(),
             func_def.name.as_str().into(),
-        ));
+        );
+        if call.call_stack.contains(&key) {
+            continue;
+        }
+        if options.exception_declarations
+            && is_stub(db, definition_file, func_def)
+            && let Some(declared) = declared_exceptions(db, definition_file, func_def)
+        {
+            errors.extend(
+                declared
+                    .into_iter()
+                    .filter_map(|declared| {
+                        Some(FunctionRaise::direct(
+                            definition_file,
+                            declared.exception?,
+                            declared.expr.range(),
+                        ))
+                    })
+                    .filter(|e| {
+                        options.is_target(e.name())
+                            && !call.exception_capture_stack.is_captured(e.name())
+                    })
+                    .map(|e| e.transitive(call.file, call.range)),
+            );
+            continue;
+        }
+        let new_stack = call.call_stack.push(key);
         let transitive_errors = get_transitive_errors(
             db,
             definition_file,
             func_def,
-            &target_exceptions,
+            options,
             new_stack,
-            &exception_capture_stack,
+            call.exception_capture_stack,
         );
         let transitive_errors = transitive_errors
             .iter()
-            .map(|e| e.transitive(expr_file, expr_range));
+            .map(|e| e.transitive(call.file, call.range));
         errors.extend(transitive_errors);
     }
     errors
 }
 
+/// Returns the errors raised by calling the method defined at `method_range` in `method_file`
+/// at `call`.
+pub(crate) fn extract_method_errors(
+    db: &dyn Db,
+    call: &CallSite,
+    method_file: File,
+    method_range: TextRange,
+    options: &AnalysisOptions,
+) -> Vec<FunctionRaise> {
+    let module = parsed_module(db, method_file).load(db);
+    function_errors(db, call, method_file, &module, method_range, options)
+}
+
+/// Looks up the method `name` on the class defined by `class_definition`, falling back to the
+/// classes of its method resolution order. Returns the file and range of the method's `def`.
+///
+/// Methods of classes in vendored stubs are skipped, as their bodies carry no information.
+pub(crate) fn find_method<'db>(
+    db: &'db dyn Db,
+    class_definition: Definition<'db>,
+    name: &str,
+) -> Option<(File, TextRange)> {
+    let class_file = class_definition.file(db);
+    let module = parsed_module(db, class_file).load(db);
+    let (class_file, class_definition) = resolve_alias(db, &module, class_file, class_definition)?;
+    if !matches!(class_file.path(db), ruff_db::files::FilePath::System(_)) {
+        return None;
+    }
+    let module = parsed_module(db, class_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    let full_range = class_definition.full_range(db, &module);
+    let cls = module_collector.find_class(&full_range.range())?;
+
+    let mro = mro_definitions(db, class_file, cls);
+    if mro.is_empty() {
+        return find_own_method(db, class_definition, name);
+    }
+    mro.into_iter()
+        .find_map(|class| find_own_method(db, class, name))
+}
+
+/// Looks up the method `name` defined in the body of the class defined by `class_definition`,
+/// ignoring its bases. Returns the file and range of the method's `def`.
+pub(crate) fn find_own_method<'db>(
+    db: &'db dyn Db,
+    class_definition: Definition<'db>,
+    name: &str,
+) -> Option<(File, TextRange)> {
+    let class_file = class_definition.file(db);
+    let module = parsed_module(db, class_file).load(db);
+    let (class_file, class_definition) = resolve_alias(db, &module, class_file, class_definition)?;
+    if !matches!(class_file.path(db), ruff_db::files::FilePath::System(_)) {
+        return None;
+    }
+    let module = parsed_module(db, class_file).load(db);
+    let mut module_collector = ModuleCollector::new();
+    module_collector.init(&module);
+    let full_range = class_definition.full_range(db, &module);
+    let cls = module_collector.find_class(&full_range.range())?;
+    let method = cls.body.iter().find_map(|stmt| {
+        stmt.as_function_def_stmt()
+            .filter(|func| func.name.as_str() == name)
+    })?;
+    Some((class_file, method.range))
+}
+
+/// Returns the definitions of the classes in the method resolution order of `cls`, which is
+/// defined in `file`, starting with `cls` itself. Empty if the MRO can't be inferred.
+pub(crate) fn mro_definitions<'db>(
+    db: &'db dyn Db,
+    file: File,
+    cls: &StmtClassDef,
+) -> Vec<Definition<'db>> {
+    let model = SemanticModel::new(db, file);
+    let Type::ClassLiteral(class) = cls.inferred_type(&model) else {
+        return vec![];
+    };
+    class
+        .iter_mro(db, None)
+        .filter_map(|base| match base {
+            ClassBase::Class(class) => Some(class.class_literal(db).0.definition(db)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Resolves the base classes of `cls`, which is defined in `file`.
+pub(crate) fn base_class_definitions<'db>(
+    db: &'db dyn Db,
+    file: File,
+    cls: &StmtClassDef,
+) -> Vec<Definition<'db>> {
+    cls.bases()
+        .iter()
+        .flat_map(|b| match b {
+            Expr::Name(name) => definitions_for_name(db, file, name),
+            Expr::Attribute(attr) => definitions_for_attribute(db, file, attr),
+            _ => vec![],
+        })
+        .filter_map(|def| match def {
+            ResolvedDefinition::Definition(def) => Some(def),
+            _ => None,
+        })
+        .collect()
+}
+
 #[allow(clippy::too_many_arguments)]
 #[salsa::tracked(returns(clone), no_eq, heap_size=ruff_memory_usage::heap_size)]
 pub fn extract_exception<'db>(
@@ -109,27 +285,157 @@ pub fn extract_exception<'db>(
     let full_range = definition.full_range(db, &module);
 
     let cls = module_collector.find_class(&full_range.range())?;
-    let bases = cls.bases();
+    let bases = base_class_definitions(db, definition_file, cls)
+        .into_iter()
+        .filter_map(|def| extract_exception(db, def.file(db), def))
+        .collect::<Vec<_>>();
 
-    let bases = bases
-        .iter()
-        .filter_map(|b| b.as_name_expr())
-        .flat_map(|b| {
-            let defs = definitions_for_name(db, definition_file, b);
-            defs.iter()
-                .filter_map(|def| {
-                    if let ResolvedDefinition::Definition(def) = def {
-                        let inner_definition_file = def.file(db);
-                        extract_exception(db, inner_definition_file, *def)
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<_>>()
+    let qualified_name = match file_to_module(db, definition_file) {
+        Some(module) => format!("{}.{}", module.name(db), cls.name),
+        None => cls.name.to_string(),
+    };
+
+    Some(Exception::new(cls.name.to_string(), qualified_name, bases))
+}
+
+/// Resolves a dotted exception name as written in documentation, e.g. `errors.NotFound`, to an
+/// exception class.
+///
+/// The first component is looked up in the body of `func` and its enclosing scopes if it's
+/// given, in the global scope of `file` otherwise, and then in `builtins`. The remaining
+/// components are resolved as module members. Names that don't start with an imported symbol
+/// are tried as fully qualified paths, e.g. `json.JSONDecodeError`.
+pub(crate) fn resolve_exception_name(
+    db: &dyn Db,
+    file: File,
+    func: Option<&StmtFunctionDef>,
+    name: &str,
+) -> Option<Exception> {
+    // Exception groups are documented with the exceptions they contain, e.g.
+    // `ExceptionGroup[ValueError, KeyError]`.
+    if let Some((group, contained)) = name.split_once('[') {
+        let group = resolve_exception_name(db, file, func, group.trim())?;
+        let contained = split_names(contained.strip_suffix(']')?)
+            .map(|name| resolve_exception_name(db, file, func, name.trim()))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(Exception::group(&group, contained));
+    }
+    let parts = name.split('.').collect::<Vec<_>>();
+    let (first, rest) = parts.split_first()?;
+
+    let mut resolved = match func {
+        Some(func) => resolve_function_symbol(db, file, func, first),
+        None => resolve_global_symbol(db, file, first),
+    };
+    if resolved.is_empty() && rest.is_empty() {
+        let builtins = ModuleName::new_static("builtins")?;
+        let builtins_file = resolve_module(db, &builtins)?.file(db)?;
+        resolved = resolve_global_symbol(db, builtins_file, first);
+    }
+    for part in rest {
+        resolved = resolved
+            .into_iter()
+            .flat_map(|def| match def {
+                ResolvedDefinition::Module(module_file) => {
+                    resolve_global_symbol(db, module_file, part)
+                }
+                _ => vec![],
+            })
+            .collect();
+    }
+    if let Some(exception) = exception_from_resolved_definitions(db, resolved) {
+        return Some(exception);
+    }
+    resolve_qualified_exception_name(db, name)
+}
+
+/// Resolves a fully qualified exception name, e.g. `json.JSONDecodeError`, to an exception
+/// class.
+pub(crate) fn resolve_qualified_exception_name(db: &dyn Db, name: &str) -> Option<Exception> {
+    exception_from_resolved_definitions(db, resolve_qualified_name(db, name)?)
+}
+
+/// Returns the exception group raised for `contained`, which is an `ExceptionGroup` unless some
+/// of them aren't subclasses of `Exception`.
+pub(crate) fn exception_group(db: &dyn Db, contained: Vec<Exception>) -> Option<Exception> {
+    let exception = resolve_qualified_exception_name(db, "builtins.Exception")?;
+    let group = if contained.iter().all(|e| e.is_subclass_of(&exception)) {
+        "builtins.ExceptionGroup"
+    } else {
+        "builtins.BaseExceptionGroup"
+    };
+    let group = resolve_qualified_exception_name(db, group)?;
+    Some(Exception::group(&group, contained))
+}
+
+/// Resolves a fully qualified name of a module-level symbol, e.g. `requests.get`, following
+/// re-exports to where the symbol is defined.
+pub(crate) fn resolve_qualified_name<'db>(
+    db: &'db dyn Db,
+    name: &str,
+) -> Option<Vec<ResolvedDefinition<'db>>> {
+    let parts = name.split('.').collect::<Vec<_>>();
+    let (symbol_name, module_components) = parts.split_last()?;
+    if module_components.is_empty() {
+        return None;
+    }
+    let module_name = ModuleName::from_components(module_components.iter().copied())?;
+    let module_file = resolve_module(db, &module_name)?.file(db)?;
+    Some(resolve_global_symbol(db, module_file, symbol_name))
+}
+
+/// Resolves a symbol defined or imported in the global scope of `file`, following imports.
+fn resolve_global_symbol<'db>(
+    db: &'db dyn Db,
+    file: File,
+    name: &str,
+) -> Vec<ResolvedDefinition<'db>> {
+    resolve_scope_symbol(db, global_scope(db, file), name)
+}
+
+/// Resolves a symbol visible in the body of `func`, which is defined in `file`, following
+/// imports. The function's scope is searched first, followed by the enclosing function scopes
+/// and the global scope.
+fn resolve_function_symbol<'db>(
+    db: &'db dyn Db,
+    file: File,
+    func: &StmtFunctionDef,
+    name: &str,
+) -> Vec<ResolvedDefinition<'db>> {
+    let index = semantic_index(db, file);
+    let function_scope = index.node_scope(NodeWithScopeRef::Function(func));
+    index
+        .ancestor_scopes(function_scope)
+        // The bodies of classes aren't visible to the functions defined in them.
+        .filter(|(scope_id, scope)| {
+            *scope_id == function_scope || !matches!(scope.kind(), ScopeKind::Class)
         })
-        .collect::<Vec<_>>();
+        .map(|(scope_id, _)| resolve_scope_symbol(db, scope_id.to_scope_id(db, file), name))
+        .find(|resolved| !resolved.is_empty())
+        .unwrap_or_default()
+}
 
-    Some(Exception::new(cls.name.to_string(), bases))
+fn resolve_scope_symbol<'db>(
+    db: &'db dyn Db,
+    scope: ScopeId<'db>,
+    name: &str,
+) -> Vec<ResolvedDefinition<'db>> {
+    find_symbol_in_scope(db, scope, name)
+        .into_iter()
+        .flat_map(|def| {
+            resolve_definition(db, def, Some(name), ImportAliasResolution::ResolveAliases)
+        })
+        .collect()
+}
+
+fn exception_from_resolved_definitions<'db>(
+    db: &'db dyn Db,
+    resolved: Vec<ResolvedDefinition<'db>>,
+) -> Option<Exception> {
+    resolved.into_iter().find_map(|def| match def {
+        ResolvedDefinition::Definition(def) => extract_exception(db, def.file(db), def),
+        _ => None,
+    })
 }
 
 pub(crate) fn extract_caught_exceptions(
@@ -143,22 +449,39 @@ pub(crate) fn extract_caught_exceptions(
     let Some(ref type_) = handler.type_ else {
         return vec![Exception::base_exception()];
     };
-    if let Expr::Name(_) = &**type_ {
-        let Some(exception) = try_extract_exception_from_expr(db, file, type_) else {
-            return vec![];
-        };
-        return vec![exception];
-    } else if let Expr::Tuple(ExprTuple { elts, .. }) = &**type_ {
+    caught_exceptions_from_expr(db, file, type_)
+}
+
+/// Resolves the exceptions an `except` clause's type expression catches, e.g. `ValueError`,
+/// `errors.NotFound`, `(mod.A, mod.B)` or a tuple constant like `RETRYABLE_ERRORS`.
+pub(crate) fn caught_exceptions_from_expr(db: &dyn Db, file: File, expr: &Expr) -> Vec<Exception> {
+    if let Expr::Tuple(ExprTuple { elts, .. }) = expr {
         return elts
             .iter()
-            .filter(|e| e.is_name_expr())
-            .filter_map(|e| try_extract_exception_from_expr(db, file, e))
+            .flat_map(|e| caught_exceptions_from_expr(db, file, e))
             .collect();
     }
-    vec![]
+    let model = SemanticModel::new(db, file);
+    let exceptions = caught_exceptions_from_type(db, expr.inferred_type(&model));
+    if !exceptions.is_empty() {
+        return exceptions;
+    }
+    try_extract_exception_from_expr(db, file, expr)
+        .into_iter()
+        .collect()
+}
+
+fn caught_exceptions_from_type<'db>(db: &'db dyn Db, ty: Type<'db>) -> Vec<Exception> {
+    if let Some(tuple) = ty.tuple_instance_spec(db) {
+        return tuple
+            .all_elements()
+            .flat_map(|element| caught_exceptions_from_type(db, *element))
+            .collect();
+    }
+    exceptions_from_type(db, ty)
 }
 
-fn resolve_alias<'a>(
+pub(crate) fn resolve_alias<'a>(
     db: &'a dyn Db,
     module: &ParsedModuleRef,
     def_file: File,
@@ -202,3 +525,33 @@ pub(crate) fn try_extract_exception_from_expr(
     }
     None
 }
+
+/// Infers the exceptions raised by a `raise` statement's expression, e.g. `raise ValueError`,
+/// `raise err` or `raise self.error.with_traceback(tb)`.
+///
+/// The expression's type has to be an exception class or instance, or a union of them.
+pub(crate) fn try_extract_raised_exceptions(
+    db: &dyn Db,
+    file: File,
+    expr: &Expr,
+) -> Vec<Exception> {
+    let model = SemanticModel::new(db, file);
+    exceptions_from_type(db, expr.inferred_type(&model))
+}
+
+fn exceptions_from_type<'db>(db: &'db dyn Db, ty: Type<'db>) -> Vec<Exception> {
+    if let Type::Union(union) = ty {
+        return union
+            .elements(db)
+            .iter()
+            .flat_map(|element| exceptions_from_type(db, *element))
+            .collect();
+    }
+    let Some(TypeDefinition::Class(definition)) = ty.definition(db) else {
+        return vec![];
+    };
+    extract_exception(db, definition.file(db), definition)
+        .filter(|exception| exception.is_subclass_of(&Exception::base_exception()))
+        .into_iter()
+        .collect()
+}
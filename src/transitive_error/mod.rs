@@ -1,6 +1,16 @@
 pub(crate) mod call_stack;
+pub(crate) mod callback;
 pub(crate) mod capture_stack;
+pub(crate) mod context_manager;
+pub(crate) mod declaration;
+pub(crate) mod decorator;
+pub(crate) mod dispatch;
+pub(crate) mod dunder;
 pub(crate) mod exception;
 pub(crate) mod extract;
+pub(crate) mod narrowing;
+pub(crate) mod options;
 pub(crate) mod raise;
+pub(crate) mod reachability;
+pub(crate) mod stdlib;
 pub(crate) mod visitor;
@@ -0,0 +1,132 @@
+use ruff_db::files::File;
+use ruff_python_ast::{BoolOp, CmpOp, Expr, Pattern};
+use ty_project::Db;
+
+use crate::transitive_error::{
+    exception::Exception,
+    extract::{caught_exceptions_from_expr, try_extract_exception_from_expr},
+};
+
+/// An exception class the caught exception is narrowed to.
+#[derive(Debug, Clone)]
+pub(crate) struct Narrowing {
+    pub(crate) exception: Exception,
+    /// Whether subclasses are excluded, as by `type(exc) is Timeout`.
+    pub(crate) exact: bool,
+}
+
+impl Narrowing {
+    fn subclasses(exception: Exception) -> Self {
+        Self {
+            exception,
+            exact: false,
+        }
+    }
+
+    /// Whether the narrowing admits `exception`.
+    pub(crate) fn admits(&self, exception: &Exception) -> bool {
+        if self.exact {
+            exception.qualified_name == self.exception.qualified_name
+        } else {
+            exception.is_subclass_of(&self.exception)
+        }
+    }
+}
+
+/// Returns the exceptions the caught exception bound to `name` is narrowed to in the body of a
+/// branch with the condition `test`, e.g. `Timeout` and `Conflict` for
+/// `isinstance(exc, (Timeout, Conflict))`.
+///
+/// `isinstance(exc, ...)`, `type(exc) is ...` and `type(exc) == ...` narrow, as do `or`
+/// combinations of them. Returns `None` for other conditions.
+pub(crate) fn narrowed_by_test(
+    db: &dyn Db,
+    file: File,
+    test: &Expr,
+    name: &str,
+) -> Option<Vec<Narrowing>> {
+    match test {
+        Expr::Call(call) if is_call_of(&call.func, "isinstance") => {
+            let [subject, types] = &*call.arguments.args else {
+                return None;
+            };
+            is_name(subject, name).then(|| {
+                caught_exceptions_from_expr(db, file, types)
+                    .into_iter()
+                    .map(Narrowing::subclasses)
+                    .collect()
+            })
+        }
+        Expr::Compare(compare) => {
+            let ([op], [comparator]) = (&*compare.ops, &*compare.comparators) else {
+                return None;
+            };
+            if !matches!(op, CmpOp::Is | CmpOp::Eq) {
+                return None;
+            }
+            let Expr::Call(call) = &*compare.left else {
+                return None;
+            };
+            let [subject] = &*call.arguments.args else {
+                return None;
+            };
+            if !is_call_of(&call.func, "type") || !is_name(subject, name) {
+                return None;
+            }
+            Some(
+                try_extract_exception_from_expr(db, file, comparator)
+                    .into_iter()
+                    .map(|exception| Narrowing {
+                        exception,
+                        exact: true,
+                    })
+                    .collect(),
+            )
+        }
+        Expr::BoolOp(bool_op) if bool_op.op == BoolOp::Or => bool_op
+            .values
+            .iter()
+            .map(|value| narrowed_by_test(db, file, value, name))
+            .collect::<Option<Vec<_>>>()
+            .map(|narrowed| narrowed.into_iter().flatten().collect()),
+        _ => None,
+    }
+}
+
+/// Returns the exceptions a `case` pattern matching the caught exception narrows it to, e.g.
+/// `Timeout` for `case Timeout():`.
+///
+/// Returns `None` for patterns that match any exception, like `case _:` or `case exc:`, and
+/// for patterns that don't narrow to classes.
+pub(crate) fn narrowed_by_pattern(
+    db: &dyn Db,
+    file: File,
+    pattern: &Pattern,
+) -> Option<Vec<Narrowing>> {
+    match pattern {
+        Pattern::MatchClass(class) => Some(
+            try_extract_exception_from_expr(db, file, &class.cls)
+                .into_iter()
+                .map(Narrowing::subclasses)
+                .collect(),
+        ),
+        Pattern::MatchAs(match_as) => narrowed_by_pattern(db, file, match_as.pattern.as_deref()?),
+        Pattern::MatchOr(match_or) => match_or
+            .patterns
+            .iter()
+            .map(|pattern| narrowed_by_pattern(db, file, pattern))
+            .collect::<Option<Vec<_>>>()
+            .map(|narrowed| narrowed.into_iter().flatten().collect()),
+        _ => None,
+    }
+}
+
+fn is_call_of(func: &Expr, builtin: &str) -> bool {
+    func.as_name_expr()
+        .is_some_and(|func| func.id.as_str() == builtin)
+}
+
+fn is_name(expr: &Expr, name: &str) -> bool {
+    expr.as_name_expr()
+        .is_some_and(|expr| expr.id.as_str() == name)
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use ruff_db::files::File;
+use ruff_text_size::TextRange;
+
+use crate::{
+    ExceptionContracts, Settings,
+    transitive_error::{
+        call_stack::CallStack, capture_stack::ExceptionCaptureStack, exception::Exception,
+    },
+};
+
+/// Options that change which errors are collected for a function.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct AnalysisOptions {
+    /// Only collect subclasses of these exceptions, or every exception if empty.
+    pub(crate) target_exceptions: Vec<Exception>,
+    /// Include the errors of overriding methods of subclasses and methods of protocol
+    /// implementations in method calls.
+    pub(crate) virtual_dispatch: bool,
+    /// Include the known exceptions of standard library callables and builtin operations.
+    pub(crate) stdlib_exceptions: bool,
+    /// Exceptions declared for callables, used instead of analyzing their definitions.
+    pub(crate) contracts: Arc<ExceptionContracts>,
+    /// Trust the exceptions declared by functions whose bodies can't be analyzed.
+    pub(crate) exception_declarations: bool,
+}
+
+impl AnalysisOptions {
+    pub(crate) fn new(target_exceptions: Vec<Exception>, settings: &Settings) -> Self {
+        Self {
+            target_exceptions,
+            virtual_dispatch: settings.virtual_dispatch,
+            stdlib_exceptions: settings.stdlib_exceptions,
+            contracts: Arc::new(settings.contracts.clone()),
+            exception_declarations: settings.exception_declarations,
+        }
+    }
+
+    /// Whether `exception` is collected given the target exceptions.
+    ///
+    /// Exception groups are collected if any exception they contain is.
+    pub(crate) fn is_target(&self, exception: &Exception) -> bool {
+        self.target_exceptions.is_empty()
+            || self
+                .target_exceptions
+                .iter()
+                .any(|target| exception.is_subclass_of(target))
+            || exception.contained.iter().any(|e| self.is_target(e))
+    }
+}
+
+/// A call a function's errors are collected for: the call expression, along with the functions
+/// it's called from and the exceptions the enclosing `try` statements catch.
+pub(crate) struct CallSite<'a> {
+    pub(crate) file: File,
+    pub(crate) range: TextRange,
+    pub(crate) call_stack: &'a CallStack,
+    pub(crate) exception_capture_stack: &'a ExceptionCaptureStack,
+}
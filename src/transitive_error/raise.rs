@@ -17,6 +17,15 @@ pub(crate) struct FunctionRaiseTransitiveTarget {
     exception: Exception,
     range: TextRange,
     depth: usize,
+    /// The overriding method the call was dispatched to, for errors of subclass overrides.
+    dispatched_to: Option<DispatchTarget>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, get_size2::GetSize)]
+pub(crate) struct DispatchTarget {
+    file: File,
+    range: TextRange,
+    qualified_name: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, get_size2::GetSize)]
@@ -35,14 +44,14 @@ impl FunctionRaise {
     }
     pub(crate) fn sort_key(&self) -> (String, usize, usize) {
         match self {
-            FunctionRaise::Direct(e) => (e.exception.name.clone(), 0, 0),
-            FunctionRaise::Transitive(e) => (e.exception.name.clone(), 1, e.depth),
+            FunctionRaise::Direct(e) => (e.exception.key(), 0, 0),
+            FunctionRaise::Transitive(e) => (e.exception.key(), 1, e.depth),
         }
     }
     pub(crate) fn group_key(&self) -> String {
         match self {
-            FunctionRaise::Direct(e) => e.exception.name.clone(),
-            FunctionRaise::Transitive(e) => e.exception.name.clone(),
+            FunctionRaise::Direct(e) => e.exception.key(),
+            FunctionRaise::Transitive(e) => e.exception.key(),
         }
     }
     pub(crate) fn transitive(&self, file: File, range: TextRange) -> Self {
@@ -54,6 +63,7 @@ impl FunctionRaise {
                     exception: (*exception).clone(),
                     range,
                     depth: 1,
+                    dispatched_to: None,
                 })
             }
             FunctionRaise::Transitive(FunctionRaiseTransitiveTarget {
@@ -64,6 +74,7 @@ impl FunctionRaise {
                 exception: (*exception).clone(),
                 range,
                 depth: depth + 1,
+                dispatched_to: None,
             }),
         }
     }
@@ -73,37 +84,66 @@ impl FunctionRaise {
             FunctionRaise::Transitive(r) => &r.exception,
         }
     }
-}
+    /// Replaces the raised exception, keeping where it's raised.
+    pub(crate) fn with_exception(mut self, exception: Exception) -> Self {
+        match &mut self {
+            FunctionRaise::Direct(r) => r.exception = exception,
+            FunctionRaise::Transitive(r) => r.exception = exception,
+        }
+        self
+    }
 
-impl From<&FunctionRaise> for Diagnostic {
-    fn from(val: &FunctionRaise) -> Self {
-        match val {
+    /// Marks the error as raised by the overriding method `qualified_name`, whose name is at
+    /// `range` in `file`, that the call it's raised through was dispatched to.
+    pub(crate) fn dispatched_to(
+        mut self,
+        file: File,
+        range: TextRange,
+        qualified_name: String,
+    ) -> Self {
+        if let FunctionRaise::Transitive(r) = &mut self {
+            r.dispatched_to = Some(DispatchTarget {
+                file,
+                range,
+                qualified_name,
+            });
+        }
+        self
+    }
+
+    /// Creates a diagnostic pointing at where the error is raised, or at the call it's raised
+    /// through along with the rest of the call chain.
+    pub(crate) fn diagnostic(&self, id: &'static str, message: String) -> Diagnostic {
+        let mut diagnostic = Diagnostic::new(
+            DiagnosticId::Lint(LintName::of(id)),
+            Severity::Error,
+            message,
+        );
+        match self {
             FunctionRaise::Direct(direct) => {
-                let mut diagnostic = Diagnostic::new(
-                    DiagnosticId::Lint(LintName::of("raise")),
-                    Severity::Error,
-                    format!("Raises undocumented error {}", direct.exception.name,),
-                );
                 diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(
                     direct.file,
                     direct.range,
                 ))));
-                diagnostic
             }
             FunctionRaise::Transitive(transitive) => {
-                let mut diagnostic = Diagnostic::new(
-                    DiagnosticId::Lint(LintName::of("raise")),
-                    Severity::Error,
-                    format!("Raises undocumented error {}", transitive.exception.name),
-                );
                 diagnostic.annotate(Annotation::primary(Span::from(FileRange::new(
                     transitive.file,
                     transitive.range,
                 ))));
-                build_call_chain(&mut diagnostic, val);
-                diagnostic
+                build_call_chain(&mut diagnostic, self);
             }
         }
+        diagnostic
+    }
+}
+
+impl From<&FunctionRaise> for Diagnostic {
+    fn from(val: &FunctionRaise) -> Self {
+        val.diagnostic(
+            "raise",
+            format!("Raises undocumented error {}", val.name().name),
+        )
     }
 }
 
@@ -118,6 +158,15 @@ fn build_call_chain(diagnostic: &mut Diagnostic, e: &FunctionRaise) {
             diagnostic.annotate(Annotation::secondary(Span::from(FileRange::new(
                 t.file, t.range,
             ))));
+            if let Some(dispatch) = &t.dispatched_to {
+                diagnostic.annotate(
+                    Annotation::secondary(Span::from(FileRange::new(
+                        dispatch.file,
+                        dispatch.range,
+                    )))
+                    .message(format!("Dispatched to {}", dispatch.qualified_name)),
+                );
+            }
             build_call_chain(diagnostic, &t.target);
         }
     }
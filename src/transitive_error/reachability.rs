@@ -0,0 +1,97 @@
+use ruff_db::files::File;
+use ruff_python_ast::{BoolOp, Expr, Stmt, UnaryOp};
+use ty_project::Db;
+use ty_python_semantic::{
+    HasType, ResolvedDefinition, SemanticModel, definitions_for_attribute, definitions_for_name,
+    file_to_module, types::Type,
+};
+
+/// Returns the value the condition `test` statically evaluates to at runtime, if it's known.
+///
+/// Conditions like `sys.version_info >= (3, 11)` or `sys.platform == "win32"` are evaluated for
+/// the configured Python version and platform. `typing.TYPE_CHECKING` is only true for type
+/// checkers, so it's false at runtime. Other conditions aren't evaluated, even if their type is a
+/// literal, as annotations aren't guaranteed at runtime, e.g. for `if not isinstance(x, int):`.
+pub(crate) fn static_condition(db: &dyn Db, file: File, test: &Expr) -> Option<bool> {
+    if is_type_checking(db, file, test) {
+        return Some(false);
+    }
+    match test {
+        Expr::UnaryOp(unary) if unary.op == UnaryOp::Not => {
+            static_condition(db, file, &unary.operand).map(|value| !value)
+        }
+        Expr::BoolOp(bool_op) => {
+            let values = bool_op
+                .values
+                .iter()
+                .map(|value| static_condition(db, file, value))
+                .collect::<Option<Vec<_>>>()?;
+            Some(match bool_op.op {
+                BoolOp::And => values.into_iter().all(|value| value),
+                BoolOp::Or => values.into_iter().any(|value| value),
+            })
+        }
+        Expr::Compare(compare) if is_sys_check(db, file, &compare.left) => {
+            let model = SemanticModel::new(db, file);
+            match test.inferred_type(&model) {
+                Type::BooleanLiteral(value) => Some(value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Whether `expr` refers to `typing.TYPE_CHECKING` or `typing_extensions.TYPE_CHECKING`.
+fn is_type_checking(db: &dyn Db, file: File, expr: &Expr) -> bool {
+    refers_to(
+        db,
+        file,
+        expr,
+        &["typing", "typing_extensions"],
+        "TYPE_CHECKING",
+    )
+}
+
+/// Whether `expr` is `sys.version_info`, `sys.platform` or an item or slice of them.
+fn is_sys_check(db: &dyn Db, file: File, expr: &Expr) -> bool {
+    let expr = match expr {
+        Expr::Subscript(subscript) => &subscript.value,
+        _ => expr,
+    };
+    ["version_info", "platform"]
+        .iter()
+        .any(|name| refers_to(db, file, expr, &["sys"], name))
+}
+
+/// Whether `expr` refers to the global `name` of one of the `modules`, e.g. `TYPE_CHECKING` of
+/// `typing`, either directly or through an attribute of the module.
+fn refers_to(db: &dyn Db, file: File, expr: &Expr, modules: &[&str], name: &str) -> bool {
+    let definitions = match expr {
+        Expr::Name(expr_name) if expr_name.id.as_str() == name => {
+            definitions_for_name(db, file, expr_name)
+        }
+        Expr::Attribute(attr) if attr.attr.as_str() == name => {
+            definitions_for_attribute(db, file, attr)
+        }
+        _ => return false,
+    };
+    definitions.into_iter().any(|def| match def {
+        ResolvedDefinition::Definition(def) => file_to_module(db, def.file(db))
+            .is_some_and(|module| modules.contains(&module.name(db).as_str())),
+        _ => false,
+    })
+}
+
+/// Whether the statements following `stmt` in the same block are unreachable, because it
+/// returns, raises, jumps, or calls a function that never returns.
+pub(crate) fn terminates(db: &dyn Db, file: File, stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Return(_) | Stmt::Raise(_) | Stmt::Continue(_) | Stmt::Break(_) => true,
+        Stmt::Expr(expr) => {
+            let model = SemanticModel::new(db, file);
+            expr.value.inferred_type(&model).is_never()
+        }
+        _ => false,
+    }
+}
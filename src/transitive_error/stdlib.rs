@@ -0,0 +1,110 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use ruff_db::files::{File, FilePath};
+use ruff_python_ast::Expr;
+use serde::Deserialize;
+use ty_project::Db;
+use ty_python_semantic::{
+    HasType, SemanticModel, file_to_module, semantic_index::definition::Definition,
+    types::definition::TypeDefinition,
+};
+
+use crate::transitive_error::{exception::Exception, extract::resolve_exception_name};
+
+/// The version of the knowledge base format this build understands.
+const KNOWLEDGE_BASE_VERSION: u32 = 1;
+
+static KNOWLEDGE_BASE: LazyLock<KnowledgeBase> = LazyLock::new(|| {
+    let knowledge_base: KnowledgeBase = toml::from_str(include_str!("stdlib_exceptions.toml"))
+        .expect("the bundled stdlib exceptions are valid TOML");
+    assert_eq!(
+        knowledge_base.version, KNOWLEDGE_BASE_VERSION,
+        "the bundled stdlib exceptions have an unsupported version"
+    );
+    knowledge_base
+});
+
+/// Exceptions raised by standard library callables, which can't be inferred from their stubs.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KnowledgeBase {
+    version: u32,
+    /// Qualified names of the exceptions raised by each callable, keyed by its qualified name.
+    raises: HashMap<String, Vec<String>>,
+}
+
+/// Returns the qualified name of the callable `func` resolves to `definition`, e.g. `json.loads`.
+///
+/// Methods are qualified by the class of their receiver, e.g. `builtins.dict.pop` for
+/// `mapping.pop(key)`.
+pub(crate) fn callable_qualified_name(
+    db: &dyn Db,
+    file: File,
+    func: &Expr,
+    definition: Definition,
+) -> Option<String> {
+    let name = definition.name(db)?;
+    if let Expr::Attribute(attr) = func
+        && let Some(receiver) = receiver_class(db, file, &attr.value)
+    {
+        return class_qualified_name(db, receiver).map(|class_name| format!("{class_name}.{name}"));
+    }
+    file_to_module(db, definition.file(db)).map(|module| format!("{}.{name}", module.name(db)))
+}
+
+/// Whether the knowledge base lists the exceptions raised by the standard library callable with
+/// the qualified name `qualified_name`.
+pub(crate) fn is_known_callable(qualified_name: &str) -> bool {
+    KNOWLEDGE_BASE.raises.contains_key(qualified_name)
+}
+
+/// Returns the known exceptions raised by the first of the dunder methods `names` of the
+/// standard library class defined by `class_definition` that has any.
+pub(crate) fn known_dunder_exceptions(
+    db: &dyn Db,
+    file: File,
+    class_definition: Definition,
+    names: &[&str],
+) -> Vec<Exception> {
+    if !matches!(class_definition.file(db).path(db), FilePath::Vendored(_)) {
+        return vec![];
+    }
+    let Some(class_name) = class_qualified_name(db, class_definition) else {
+        return vec![];
+    };
+    names
+        .iter()
+        .map(|name| known_exceptions(db, file, &format!("{class_name}.{name}")))
+        .find(|exceptions| !exceptions.is_empty())
+        .unwrap_or_default()
+}
+
+/// Returns the known exceptions raised by calling the standard library callable with the
+/// qualified name `qualified_name`.
+pub(crate) fn known_exceptions(db: &dyn Db, file: File, qualified_name: &str) -> Vec<Exception> {
+    KNOWLEDGE_BASE
+        .raises
+        .get(qualified_name)
+        .into_iter()
+        .flatten()
+        .filter_map(|exception| resolve_exception_name(db, file, None, exception))
+        .collect()
+}
+
+/// Returns the class of the instance or class object `expr` evaluates to.
+fn receiver_class<'db>(db: &'db dyn Db, file: File, expr: &Expr) -> Option<Definition<'db>> {
+    let model = SemanticModel::new(db, file);
+    match expr.inferred_type(&model).definition(db)? {
+        TypeDefinition::Class(definition) => Some(definition),
+        _ => None,
+    }
+}
+
+fn class_qualified_name(db: &dyn Db, class_definition: Definition) -> Option<String> {
+    let module = file_to_module(db, class_definition.file(db))?;
+    Some(format!(
+        "{}.{}",
+        module.name(db),
+        class_definition.name(db)?
+    ))
+}
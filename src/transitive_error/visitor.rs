@@ -1,46 +1,148 @@
 use itertools::Itertools;
 use ruff_db::files::File;
-use ruff_python_ast::visitor::{Visitor, walk_expr, walk_stmt};
-use ruff_python_ast::{Expr, ExprCall, Stmt, StmtFunctionDef, StmtTry};
+use ruff_python_ast::visitor::{Visitor, walk_comprehension, walk_decorator, walk_expr, walk_stmt};
+use ruff_python_ast::{
+    Comprehension, Decorator, Expr, ExprCall, ExprContext, Parameters, Stmt, StmtFunctionDef,
+    StmtTry,
+};
+use ruff_text_size::{Ranged, TextRange};
 use ty_project::Db;
-use ty_python_semantic::{ResolvedDefinition, definitions_for_attribute, definitions_for_name};
+use ty_python_semantic::{
+    ResolvedDefinition, definitions_for_attribute, definitions_for_name,
+    semantic_index::definition::Definition,
+};
 
 use crate::transitive_error::call_stack::CallStack;
+use crate::transitive_error::callback::{CallbackArgument, calls_callback};
 use crate::transitive_error::capture_stack::ExceptionCaptureStack;
+use crate::transitive_error::context_manager::{
+    ContextManagerProtocol, context_manager_class, is_task_group, suppressed_exceptions,
+};
+use crate::transitive_error::decorator::apply_decorators;
+use crate::transitive_error::dispatch::find_overrides;
+use crate::transitive_error::dunder::{
+    builtin_dunder, compare_dunder, instance_class, is_property, returned_class, unary_dunder,
+};
 use crate::transitive_error::exception::Exception;
 use crate::transitive_error::extract::{
-    extract_caught_exceptions, extract_errors, try_extract_exception_from_expr,
+    exception_group, extract_caught_exceptions, extract_errors, extract_exception,
+    extract_method_errors, find_method, try_extract_raised_exceptions,
 };
+use crate::transitive_error::narrowing::{Narrowing, narrowed_by_pattern, narrowed_by_test};
+use crate::transitive_error::options::{AnalysisOptions, CallSite};
 use crate::transitive_error::raise::FunctionRaise;
+use crate::transitive_error::reachability::{static_condition, terminates};
+use crate::transitive_error::stdlib::{
+    callable_qualified_name, is_known_callable, known_dunder_exceptions, known_exceptions,
+};
 
 pub(crate) fn get_transitive_errors<'a>(
     db: &'a dyn Db,
     file: File,
     func: &'a StmtFunctionDef,
-    target_exceptions: &Vec<Exception>,
+    options: &AnalysisOptions,
     call_stack: CallStack,
     exception_capture_stack: &'a ExceptionCaptureStack,
 ) -> Vec<FunctionRaise> {
-    FunctionTransitiveErrorVisitor::new(
+    let errors = FunctionTransitiveErrorVisitor::new(
         db,
         file,
         func,
-        target_exceptions,
-        call_stack,
+        options,
+        call_stack.clone(),
         exception_capture_stack,
     )
-    .transitive_errors()
+    .transitive_errors();
+    apply_decorators(
+        db,
+        file,
+        func,
+        errors,
+        options,
+        &call_stack,
+        exception_capture_stack,
+    )
+}
+
+/// The result of analyzing a function that's checked, rather than called by a checked function.
+pub(crate) struct FunctionAnalysis {
+    pub(crate) errors: Vec<FunctionRaise>,
+    /// The exceptions caught by `except` clauses that their `try` bodies never raise, along
+    /// with the range of each clause's type expression.
+    pub(crate) dead_handlers: Vec<(Vec<Exception>, TextRange)>,
+    pub(crate) unreachable_handlers: Vec<UnreachableHandler>,
+}
+
+/// An `except` clause that never runs, because earlier clauses of the same `try` statement catch
+/// all of its exceptions.
+pub(crate) struct UnreachableHandler {
+    /// The range of the clause's type expression.
+    pub(crate) range: TextRange,
+    /// An exception caught by an earlier clause that shadows the clause.
+    pub(crate) shadowed_by: Exception,
+    /// The range of the earlier clause's type expression.
+    pub(crate) shadowing_range: TextRange,
+}
+
+/// Analyzes the function `func`, returning its errors along with the findings about its
+/// `except` clauses.
+pub(crate) fn analyze_function(
+    db: &dyn Db,
+    file: File,
+    func: &StmtFunctionDef,
+    options: &AnalysisOptions,
+) -> FunctionAnalysis {
+    let exception_capture_stack = ExceptionCaptureStack::new();
+    let mut visitor = FunctionTransitiveErrorVisitor::new(
+        db,
+        file,
+        func,
+        options,
+        CallStack::new(),
+        &exception_capture_stack,
+    );
+    let errors = visitor.transitive_errors();
+    let errors = apply_decorators(
+        db,
+        file,
+        func,
+        errors,
+        options,
+        &CallStack::new(),
+        &exception_capture_stack,
+    );
+    FunctionAnalysis {
+        errors,
+        dead_handlers: visitor.dead_handlers,
+        unreachable_handlers: visitor.unreachable_handlers,
+    }
 }
 
 pub(crate) struct FunctionTransitiveErrorVisitor<'a> {
     db: &'a dyn Db,
     file: File,
     func: &'a StmtFunctionDef,
-    target_exceptions: &'a Vec<Exception>,
+    options: &'a AnalysisOptions,
     errors: Vec<FunctionRaise>,
     call_stack: CallStack,
     exception_capture_stack: ExceptionCaptureStack,
     try_block_exceptions: Vec<Vec<Exception>>,
+    /// Names the exceptions of the enclosing `except` clauses are bound to, e.g. `e` for
+    /// `except ValueError as e:`.
+    handler_names: Vec<Option<&'a str>>,
+    /// The exceptions the caught exceptions of the enclosing `except` clauses are narrowed to by
+    /// `isinstance` checks or `match` statements, or `None` if they aren't narrowed.
+    narrowed_exceptions: Vec<Option<Vec<Narrowing>>>,
+    /// The exceptions caught by `except` clauses that their `try` bodies never raise, along
+    /// with the range of each clause's type expression.
+    dead_handlers: Vec<(Vec<Exception>, TextRange)>,
+    unreachable_handlers: Vec<UnreachableHandler>,
+    /// The number of calls, raises and implicit dunder calls whose exceptions are unknown, e.g.
+    /// calls of standard library functions the knowledge base doesn't list.
+    unanalyzed: usize,
+    /// The parameter a decorator wrapper receives the decorated function in, and the errors of
+    /// the decorated function.
+    wrapped: Option<(&'a str, &'a [FunctionRaise])>,
 }
 
 impl<'a> FunctionTransitiveErrorVisitor<'a> {
@@ -48,7 +150,7 @@ impl<'a> FunctionTransitiveErrorVisitor<'a> {
         db: &'a dyn Db,
         file: File,
         func: &'a StmtFunctionDef,
-        target_exceptions: &'a Vec<Exception>,
+        options: &'a AnalysisOptions,
         call_stack: CallStack,
         exception_capture_stack: &'a ExceptionCaptureStack,
     ) -> Self {
@@ -56,14 +158,26 @@ impl<'a> FunctionTransitiveErrorVisitor<'a> {
             db,
             file,
             func,
-            target_exceptions,
+            options,
             errors: vec![],
             call_stack,
             exception_capture_stack: exception_capture_stack.clone(),
             try_block_exceptions: vec![],
+            handler_names: vec![],
+            narrowed_exceptions: vec![],
+            dead_handlers: vec![],
+            unreachable_handlers: vec![],
+            unanalyzed: 0,
+            wrapped: None,
         }
     }
 
+    /// Treats calls of the parameter `name` as calls of a decorated function raising `errors`.
+    pub(crate) fn wrapping(mut self, name: &'a str, errors: &'a [FunctionRaise]) -> Self {
+        self.wrapped = Some((name, errors));
+        self
+    }
+
     pub(crate) fn transitive_errors(&mut self) -> Vec<FunctionRaise> {
         self.visit_body(&self.func.body);
         self.errors = self
@@ -77,9 +191,371 @@ impl<'a> FunctionTransitiveErrorVisitor<'a> {
             .collect();
         self.errors.clone()
     }
+
+    /// Adds the errors raised by calling `func` at `range`.
+    fn visit_call(&mut self, func: &Expr, range: TextRange) {
+        let errors = self.call_errors(func, range);
+        self.errors.extend(errors);
+    }
+
+    /// Returns the errors raised by calling `func` at `range` that aren't captured.
+    fn call_errors(&mut self, func: &Expr, range: TextRange) -> Vec<FunctionRaise> {
+        if let Expr::Name(name) = func
+            && let Some((wrapped_name, wrapped_errors)) = self.wrapped
+            && name.id.as_str() == wrapped_name
+        {
+            return wrapped_errors
+                .iter()
+                .filter(|e| !self.exception_capture_stack.is_captured(e.name()))
+                .cloned()
+                .collect();
+        }
+        let Some(defs) = definitions_for_call_func(self.db, self.file, func) else {
+            self.unanalyzed += 1;
+            return vec![];
+        };
+        if !defs
+            .iter()
+            .any(|def| matches!(def, ResolvedDefinition::Definition(_)))
+        {
+            self.unanalyzed += 1;
+        }
+        let mut errors = vec![];
+        for def in defs {
+            if let ResolvedDefinition::Definition(def) = def {
+                if !self.options.contracts.is_empty()
+                    && let Some(declared) = self.options.contracts.declared_exceptions(
+                        self.db,
+                        def,
+                        callable_qualified_name(self.db, self.file, func, def).as_deref(),
+                    )
+                {
+                    errors.extend(self.known_errors(declared, range));
+                    continue;
+                }
+                let definition_file = def.file(self.db);
+                let definition_path = match definition_file.path(self.db) {
+                    ruff_db::files::FilePath::System(path) => path,
+                    ruff_db::files::FilePath::SystemVirtual(_) => {
+                        self.unanalyzed += 1;
+                        continue;
+                    }
+                    ruff_db::files::FilePath::Vendored(_) => {
+                        // Creating an exception doesn't raise one.
+                        if extract_exception(self.db, definition_file, def)
+                            .is_some_and(|e| e.is_subclass_of(&Exception::base_exception()))
+                        {
+                            continue;
+                        }
+                        let qualified_name = callable_qualified_name(self.db, self.file, func, def)
+                            .filter(|name| {
+                                self.options.stdlib_exceptions && is_known_callable(name)
+                            });
+                        match qualified_name {
+                            Some(qualified_name) => {
+                                let known = known_exceptions(self.db, self.file, &qualified_name);
+                                errors.extend(self.known_errors(known, range));
+                            }
+                            None => self.unanalyzed += 1,
+                        }
+                        continue;
+                    }
+                };
+                if let Some(name) = def.name(self.db) {
+                    let key = (definition_path.as_str().into(), name);
+                    if self.call_stack.contains(&key) {
+                        continue;
+                    }
+                }
+                let transitive_errors = extract_errors(
+                    self.db,
+                    self.file,
+                    range,
+                    definition_file,
+                    def,
+                    self.options.clone(),
+                    self.call_stack.clone(),
+                    self.exception_capture_stack.clone(),
+                )
+                .to_vec();
+                errors.extend(
+                    transitive_errors
+                        .into_iter()
+                        .filter(|e| !self.exception_capture_stack.is_captured(e.name())),
+                )
+            }
+        }
+        if self.options.virtual_dispatch {
+            for method in find_overrides(self.db, self.file, func) {
+                let override_errors = extract_method_errors(
+                    self.db,
+                    &self.call_site(range),
+                    method.file,
+                    method.range,
+                    self.options,
+                );
+                errors.extend(
+                    override_errors
+                        .into_iter()
+                        .filter(|e| !self.exception_capture_stack.is_captured(e.name()))
+                        .map(|e| {
+                            e.dispatched_to(
+                                method.file,
+                                method.name_range,
+                                method.qualified_name.clone(),
+                            )
+                        }),
+                );
+            }
+        }
+        errors
+    }
+
+    /// Adds the errors raised by functions and lambdas passed to `call` that the callee calls,
+    /// attributed to `call`.
+    fn visit_callbacks(&mut self, call: &'a ExprCall) {
+        let Some(defs) = definitions_for_call_func(self.db, self.file, &call.func) else {
+            return;
+        };
+        let defs = defs
+            .into_iter()
+            .filter_map(|def| match def {
+                ResolvedDefinition::Definition(def) => Some(def),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let is_method_call = call.func.is_attribute_expr();
+
+        let positional = call
+            .arguments
+            .args
+            .iter()
+            .take_while(|arg| !arg.is_starred_expr())
+            .enumerate()
+            .map(|(index, arg)| (CallbackArgument::Positional(index), arg));
+        let keywords = call.arguments.keywords.iter().filter_map(|keyword| {
+            let name = keyword.arg.as_ref()?;
+            Some((CallbackArgument::Keyword(name.as_str()), &keyword.value))
+        });
+        for (argument, callback) in positional.chain(keywords) {
+            if !matches!(
+                callback,
+                Expr::Name(_) | Expr::Attribute(_) | Expr::Lambda(_)
+            ) {
+                continue;
+            }
+            if !defs
+                .iter()
+                .any(|def| calls_callback(self.db, *def, &argument, is_method_call))
+            {
+                continue;
+            }
+            let callback_errors = if let Expr::Lambda(lambda) = callback {
+                let saved_errors_len = self.errors.len();
+                self.visit_expr(&lambda.body);
+                self.errors.split_off(saved_errors_len)
+            } else {
+                self.call_errors(callback, callback.range())
+            };
+            self.errors.extend(
+                callback_errors
+                    .iter()
+                    .map(|e| e.transitive(self.file, call.range)),
+            );
+        }
+    }
+
+    /// Adds the errors raised by calling the method `name` of the class defined by
+    /// `class_definition` at `range`.
+    fn visit_method_call(&mut self, class_definition: Definition, name: &str, range: TextRange) {
+        if let Some((method_file, method_range)) = find_method(self.db, class_definition, name) {
+            self.visit_method(method_file, method_range, range);
+        }
+    }
+
+    /// Adds the errors raised by the first of the dunder methods `names` that the class of
+    /// `receiver` defines, implicitly called at `range`.
+    fn visit_dunder_call(&mut self, receiver: &Expr, names: &[&str], range: TextRange) {
+        if let Some(class_definition) = instance_class(self.db, self.file, receiver) {
+            self.visit_class_dunder_call(class_definition, names, range);
+        }
+    }
+
+    /// Adds the errors raised by the first of the dunder methods `names` that the class defined
+    /// by `class_definition` defines, implicitly called at `range`.
+    fn visit_class_dunder_call(
+        &mut self,
+        class_definition: Definition,
+        names: &[&str],
+        range: TextRange,
+    ) {
+        if let Some((method_file, method_range)) = names
+            .iter()
+            .find_map(|name| find_method(self.db, class_definition, name))
+        {
+            self.visit_method(method_file, method_range, range);
+        } else if matches!(
+            class_definition.file(self.db).path(self.db),
+            ruff_db::files::FilePath::Vendored(_)
+        ) {
+            let known = if self.options.stdlib_exceptions {
+                known_dunder_exceptions(self.db, self.file, class_definition, names)
+            } else {
+                vec![]
+            };
+            if known.is_empty() {
+                self.unanalyzed += 1;
+            }
+            let errors = self.known_errors(known, range);
+            self.errors.extend(errors);
+        }
+    }
+
+    /// Returns the targeted, uncaught errors among the known exceptions of a standard library
+    /// call at `range`.
+    fn known_errors(&self, exceptions: Vec<Exception>, range: TextRange) -> Vec<FunctionRaise> {
+        exceptions
+            .into_iter()
+            .filter(|e| self.options.is_target(e) && !self.exception_capture_stack.is_captured(e))
+            .map(|e| FunctionRaise::direct(self.file, e, range))
+            .collect()
+    }
+
+    /// Adds the errors raised by iterating over `iter`.
+    fn visit_iteration(&mut self, iter: &Expr, is_async: bool) {
+        let (iter_dunder, next_dunder) = if is_async {
+            ("__aiter__", "__anext__")
+        } else {
+            ("__iter__", "__next__")
+        };
+        let Some(iterable_class) = instance_class(self.db, self.file, iter) else {
+            return;
+        };
+        self.visit_class_dunder_call(iterable_class, &[iter_dunder], iter.range());
+        // `__next__` is called on the iterator `__iter__` returns, which is usually the iterable
+        // itself if `__iter__` doesn't annotate another class.
+        let iterator_class = find_method(self.db, iterable_class, iter_dunder)
+            .and_then(|(method_file, method_range)| {
+                returned_class(self.db, method_file, method_range)
+            })
+            .unwrap_or(iterable_class);
+        self.visit_class_dunder_call(iterator_class, &[next_dunder], iter.range());
+    }
+
+    /// Adds the errors raised by calling the method defined at `method_range` in `method_file`
+    /// at `range`.
+    fn visit_method(&mut self, method_file: File, method_range: TextRange, range: TextRange) {
+        let transitive_errors = extract_method_errors(
+            self.db,
+            &self.call_site(range),
+            method_file,
+            method_range,
+            self.options,
+        );
+        self.errors.extend(
+            transitive_errors
+                .into_iter()
+                .filter(|e| !self.exception_capture_stack.is_captured(e.name())),
+        )
+    }
+
+    /// Returns the call at `range` in the function being analyzed.
+    fn call_site(&self, range: TextRange) -> CallSite<'_> {
+        CallSite {
+            file: self.file,
+            range,
+            call_stack: &self.call_stack,
+            exception_capture_stack: &self.exception_capture_stack,
+        }
+    }
+
+    /// Whether `expr` refers to the exception caught by the innermost `except` clause.
+    fn is_handler_name(&self, expr: &Expr) -> bool {
+        let Expr::Name(name) = expr else {
+            return false;
+        };
+        self.handler_names
+            .last()
+            .is_some_and(|handler_name| *handler_name == Some(name.id.as_str()))
+    }
+
+    /// Returns the exceptions the condition `test` narrows the caught exception to.
+    fn handler_narrowing(&self, test: &Expr) -> Option<Vec<Narrowing>> {
+        let name = (*self.handler_names.last()?)?;
+        narrowed_by_test(self.db, self.file, test, name)
+    }
+
+    /// Visits `body` with the caught exception narrowed to `narrowed`, if it's narrowed.
+    fn visit_narrowed_body(&mut self, body: &'a [Stmt], narrowed: Option<Vec<Narrowing>>) {
+        match narrowed {
+            Some(narrowed) => {
+                self.narrowed_exceptions.push(Some(narrowed));
+                self.visit_body(body);
+                self.narrowed_exceptions.pop();
+            }
+            None => self.visit_body(body),
+        }
+    }
+
+    /// Restricts the exceptions `reraised` by a re-raise to the ones the caught exception is
+    /// narrowed to, including the exceptions of the `try` body they catch.
+    ///
+    /// If none of them are admitted, the `try` body may raise the narrowed exceptions through
+    /// code the analysis doesn't follow, so the narrowed exceptions the clause catches are
+    /// re-raised.
+    fn narrow_reraised(&self, reraised: Vec<Exception>) -> Vec<Exception> {
+        let Some(Some(narrowed)) = self.narrowed_exceptions.last() else {
+            return reraised;
+        };
+        let try_exceptions = self
+            .try_block_exceptions
+            .last()
+            .into_iter()
+            .flatten()
+            .filter(|e| reraised.iter().any(|r| e.is_subclass_of(r)));
+        let admitted = reraised
+            .iter()
+            .chain(try_exceptions)
+            .filter(|e| narrowed.iter().any(|n| n.admits(e)))
+            .unique_by(|e| e.key())
+            .cloned()
+            .collect::<Vec<_>>();
+        if !admitted.is_empty() {
+            return admitted;
+        }
+        let caught = self
+            .exception_capture_stack
+            .get_current_handler_exceptions()
+            .unwrap_or_default();
+        narrowed
+            .iter()
+            .map(|n| &n.exception)
+            .filter(|e| caught.iter().any(|c| e.is_subclass_of(c)))
+            .unique_by(|e| e.key())
+            .cloned()
+            .collect()
+    }
+
+    /// Visits the default values of `parameters`, which are evaluated when a function is defined.
+    fn visit_parameter_defaults(&mut self, parameters: &'a Parameters) {
+        for parameter in parameters.iter_non_variadic_params() {
+            if let Some(default) = &parameter.default {
+                self.visit_expr(default);
+            }
+        }
+    }
 }
 
 impl<'a> Visitor<'a> for FunctionTransitiveErrorVisitor<'a> {
+    fn visit_body(&mut self, body: &'a [Stmt]) {
+        for stmt in body {
+            self.visit_stmt(stmt);
+            if terminates(self.db, self.file, stmt) {
+                break;
+            }
+        }
+    }
+
     fn visit_stmt(&mut self, stmt: &'a Stmt) {
         if let Stmt::Raise(raise) = stmt {
             if raise.exc.is_none() {
@@ -87,68 +563,57 @@ impl<'a> Visitor<'a> for FunctionTransitiveErrorVisitor<'a> {
                     .exception_capture_stack
                     .get_current_handler_exceptions()
                 {
+                    let mut reraised = vec![];
                     for exc in handler_exceptions {
                         if exc.name == "BaseException" && exc.bases.is_empty() {
                             if let Some(try_exceptions) = self.try_block_exceptions.last() {
-                                for try_exc in try_exceptions {
-                                    if self.target_exceptions.is_empty()
-                                        || self
-                                            .target_exceptions
-                                            .iter()
-                                            .any(|t| try_exc.is_subclass_of(t))
-                                    {
-                                        self.errors.extend_one(FunctionRaise::direct(
-                                            self.file,
-                                            try_exc.clone(),
-                                            raise.range,
-                                        ));
-                                    }
-                                }
+                                reraised.extend(try_exceptions.iter().cloned());
                             }
                             continue;
                         }
-                        if !self.target_exceptions.is_empty()
-                            && !self.target_exceptions.iter().any(|t| exc.is_subclass_of(t))
-                        {
-                            continue;
+                        reraised.push(exc);
+                    }
+                    for exc in self.narrow_reraised(reraised) {
+                        if self.options.is_target(&exc) {
+                            self.errors.extend_one(FunctionRaise::direct(
+                                self.file,
+                                exc,
+                                raise.range,
+                            ));
                         }
-                        self.errors.extend_one(FunctionRaise::direct(
-                            self.file,
-                            exc.clone(),
-                            raise.range,
-                        ));
                     }
                 }
-            } else if let Some(Expr::Call(ExprCall { func, .. })) = raise.exc.as_deref()
-                && let Some(exc) = try_extract_exception_from_expr(self.db, self.file, func)
-            {
-                if (self.target_exceptions.is_empty()
-                    || self.target_exceptions.iter().any(|t| exc.is_subclass_of(t)))
-                    && !self.exception_capture_stack.is_captured(&exc)
-                {
-                    self.errors
-                        .extend_one(FunctionRaise::direct(self.file, exc, raise.range));
-                }
-            } else if let Some(Expr::Name(_name_expr)) = raise.exc.as_deref()
-                && self.exception_capture_stack.in_handler()
+            } else if let Some(Expr::Name(name_expr)) = raise.exc.as_deref()
+                && self
+                    .handler_names
+                    .last()
+                    .is_some_and(|handler_name| *handler_name == Some(name_expr.id.as_str()))
                 && let Some(handler_exceptions) = self
                     .exception_capture_stack
                     .get_current_handler_exceptions()
             {
-                for exc in handler_exceptions {
-                    if exc.name == "BaseException" && exc.bases.is_empty() {
-                        continue;
+                let reraised = handler_exceptions
+                    .into_iter()
+                    .filter(|exc| !(exc.name == "BaseException" && exc.bases.is_empty()))
+                    .collect();
+                for exc in self.narrow_reraised(reraised) {
+                    if self.options.is_target(&exc) {
+                        self.errors
+                            .extend_one(FunctionRaise::direct(self.file, exc, raise.range));
                     }
-                    if !self.target_exceptions.is_empty()
-                        && !self.target_exceptions.iter().any(|t| exc.is_subclass_of(t))
+                }
+            } else if let Some(exc_expr) = raise.exc.as_deref() {
+                let raised = try_extract_raised_exceptions(self.db, self.file, exc_expr);
+                if raised.is_empty() {
+                    self.unanalyzed += 1;
+                }
+                for exc in raised {
+                    if self.options.is_target(&exc)
+                        && !self.exception_capture_stack.is_captured(&exc)
                     {
-                        continue;
+                        self.errors
+                            .extend_one(FunctionRaise::direct(self.file, exc, raise.range));
                     }
-                    self.errors.extend_one(FunctionRaise::direct(
-                        self.file,
-                        exc.clone(),
-                        raise.range,
-                    ));
                 }
             }
             walk_stmt(self, stmt);
@@ -157,13 +622,17 @@ impl<'a> Visitor<'a> for FunctionTransitiveErrorVisitor<'a> {
             handlers,
             orelse,
             finalbody,
+            is_star,
             ..
         }) = stmt
         {
             let mut try_exceptions = Vec::new();
             let saved_errors_len = self.errors.len();
+            let saved_unanalyzed = self.unanalyzed;
 
             self.visit_body(body);
+            // Clauses can only be dead if the exceptions of the whole body are known.
+            let is_body_analyzed = self.unanalyzed == saved_unanalyzed;
 
             for error in &self.errors[saved_errors_len..] {
                 let exc = error.name().clone();
@@ -177,90 +646,356 @@ impl<'a> Visitor<'a> for FunctionTransitiveErrorVisitor<'a> {
                 .flat_map(|h| extract_caught_exceptions(self.db, self.file, h))
                 .collect::<Vec<_>>();
 
-            self.exception_capture_stack =
-                self.exception_capture_stack.push(caught_exceptions.clone());
+            if *is_star {
+                // `except*` clauses split the exceptions they match off of exception groups.
+                let body_errors = self.errors.split_off(saved_errors_len);
+                self.errors
+                    .extend(body_errors.into_iter().filter_map(|error| {
+                        let remaining = error.name().split_group(&caught_exceptions)?;
+                        Some(error.with_exception(remaining))
+                    }));
+            } else {
+                self.exception_capture_stack =
+                    self.exception_capture_stack.push(caught_exceptions.clone());
 
-            let mut filtered_errors = Vec::new();
-            for (idx, error) in self.errors.iter().enumerate() {
-                if idx < saved_errors_len || !self.exception_capture_stack.is_captured(error.name())
-                {
-                    filtered_errors.push(error.clone());
+                let mut filtered_errors = Vec::new();
+                for (idx, error) in self.errors.iter().enumerate() {
+                    if idx < saved_errors_len
+                        || !self.exception_capture_stack.is_captured(error.name())
+                    {
+                        filtered_errors.push(error.clone());
+                    }
                 }
+                self.errors = filtered_errors;
+                self.exception_capture_stack = self.exception_capture_stack.pop();
             }
-            self.errors = filtered_errors;
-
-            self.try_block_exceptions.push(try_exceptions);
-            self.exception_capture_stack = self.exception_capture_stack.pop();
 
+            // Only the first `except` clause matching an exception runs, so each clause only
+            // receives the exceptions the earlier ones don't catch. Every matching `except*`
+            // clause runs.
+            let mut earlier_caught: Vec<(Exception, TextRange)> = vec![];
             for handler in handlers {
                 if let Some(except_handler) = handler.as_except_handler() {
-                    let handler_exceptions = extract_caught_exceptions(self.db, self.file, handler);
+                    let caught = extract_caught_exceptions(self.db, self.file, handler);
+                    let (handler_exceptions, dispatched) = if *is_star {
+                        // An `except*` clause receives the exceptions it matches as a group.
+                        let group = exception_group(self.db, caught).into_iter().collect();
+                        (group, try_exceptions.clone())
+                    } else {
+                        let shadowing = |e: &Exception| {
+                            earlier_caught.iter().find(|(c, _)| e.is_subclass_of(c))
+                        };
+                        let is_shadowed = |e: &Exception| shadowing(e).is_some();
+                        let reachable = caught
+                            .iter()
+                            .filter(|e| !is_shadowed(e))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        let dispatched = try_exceptions
+                            .iter()
+                            .filter(|e| !is_shadowed(e))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        let is_unreachable = !caught.is_empty() && reachable.is_empty();
+                        if is_unreachable
+                            && let Some(type_) = &except_handler.type_
+                            && let Some((shadowed_by, shadowing_range)) =
+                                caught.first().and_then(shadowing)
+                        {
+                            self.unreachable_handlers.push(UnreachableHandler {
+                                range: type_.range(),
+                                shadowed_by: shadowed_by.clone(),
+                                shadowing_range: *shadowing_range,
+                            });
+                        }
+                        if is_body_analyzed
+                            && !is_unreachable
+                            && let Some(type_) = &except_handler.type_
+                            && !caught.is_empty()
+                            && caught.iter().all(|e| {
+                                !is_broad_catch(e)
+                                    && self.options.is_target(e)
+                                    && !try_exceptions
+                                        .iter()
+                                        .any(|t| t.is_subclass_of(e) || e.is_subclass_of(t))
+                            })
+                        {
+                            self.dead_handlers.push((caught.clone(), type_.range()));
+                        }
+                        let range = except_handler
+                            .type_
+                            .as_deref()
+                            .map_or(except_handler.range, Ranged::range);
+                        earlier_caught.extend(caught.into_iter().map(|e| (e, range)));
+                        if is_unreachable {
+                            continue;
+                        }
+                        // The clause receives the body's exceptions it catches, unless the body
+                        // may raise exceptions that weren't found.
+                        let received = if is_body_analyzed {
+                            dispatched
+                                .iter()
+                                .flat_map(|e| {
+                                    if reachable.iter().any(|c| e.is_subclass_of(c)) {
+                                        vec![e.clone()]
+                                    } else {
+                                        reachable
+                                            .iter()
+                                            .filter(|c| c.is_subclass_of(e))
+                                            .cloned()
+                                            .collect()
+                                    }
+                                })
+                                .unique_by(Exception::key)
+                                .collect()
+                        } else {
+                            reachable
+                        };
+                        (received, dispatched)
+                    };
+                    self.try_block_exceptions.push(dispatched);
                     self.exception_capture_stack = self
                         .exception_capture_stack
                         .push_handler_exceptions(handler_exceptions);
+                    self.handler_names
+                        .push(except_handler.name.as_ref().map(|name| name.as_str()));
+                    self.narrowed_exceptions.push(None);
                     self.visit_body(&except_handler.body);
+                    self.narrowed_exceptions.pop();
+                    self.handler_names.pop();
                     self.exception_capture_stack =
                         self.exception_capture_stack.pop_handler_exceptions();
+                    self.try_block_exceptions.pop();
                 }
             }
-            self.try_block_exceptions.pop();
             self.visit_body(orelse);
             self.visit_body(finalbody);
+        } else if let Stmt::With(with_stmt) = stmt {
+            let protocol = ContextManagerProtocol::new(with_stmt.is_async);
+            let mut suppressed = vec![];
+            let mut task_group = None;
+            for item in &with_stmt.items {
+                self.visit_with_item(item);
+                let context_expr = &item.context_expr;
+                let Some(class_definition) =
+                    context_manager_class(self.db, self.file, context_expr)
+                else {
+                    continue;
+                };
+                for method in [protocol.enter, protocol.exit] {
+                    self.visit_method_call(class_definition, method, context_expr.range());
+                }
+                if is_task_group(self.db, class_definition) {
+                    task_group = Some(context_expr.range());
+                }
+                suppressed.extend(suppressed_exceptions(
+                    self.db,
+                    self.file,
+                    context_expr,
+                    class_definition,
+                    &protocol,
+                ));
+            }
+
+            let saved_errors_len = self.errors.len();
+            self.visit_body(&with_stmt.body);
+            let body_errors = self.errors.split_off(saved_errors_len);
+            let body_errors = body_errors.into_iter().filter(|error| {
+                !suppressed
+                    .iter()
+                    .any(|exception| error.name().is_subclass_of(exception))
+            });
+            match task_group {
+                // A task group raises the errors of its body and tasks as an exception group,
+                // which is attributed to the call chain of the first of them.
+                Some(range) => {
+                    let body_errors = body_errors.collect::<Vec<_>>();
+                    let contained = body_errors
+                        .iter()
+                        .map(|error| error.name().clone())
+                        .unique_by(Exception::key)
+                        .collect::<Vec<_>>();
+                    if let Some(first) = body_errors.first()
+                        && let Some(group) = exception_group(self.db, contained)
+                        && !self.exception_capture_stack.is_captured(&group)
+                    {
+                        self.errors
+                            .push(first.transitive(self.file, range).with_exception(group));
+                    }
+                }
+                None => self.errors.extend(body_errors),
+            }
+        } else if let Stmt::FunctionDef(func_def) = stmt {
+            // The body of a nested function only runs when it's called, which is handled at the
+            // call site.
+            for decorator in &func_def.decorator_list {
+                self.visit_decorator(decorator);
+            }
+            self.visit_parameter_defaults(&func_def.parameters);
+        } else if let Stmt::ClassDef(class_def) = stmt {
+            // A class body runs when the class is defined, its methods only when they're called.
+            for decorator in &class_def.decorator_list {
+                self.visit_decorator(decorator);
+            }
+            if let Some(arguments) = &class_def.arguments {
+                self.visit_arguments(arguments);
+            }
+            self.visit_body(&class_def.body);
+        } else if let Stmt::For(for_stmt) = stmt {
+            self.visit_iteration(&for_stmt.iter, for_stmt.is_async);
+            walk_stmt(self, stmt);
+        } else if let Stmt::If(if_stmt) = stmt {
+            // Branches whose condition is statically false never run, and neither do the ones
+            // following a branch whose condition is statically true.
+            let clauses = std::iter::once((Some(&*if_stmt.test), &if_stmt.body)).chain(
+                if_stmt
+                    .elif_else_clauses
+                    .iter()
+                    .map(|clause| (clause.test.as_ref(), &clause.body)),
+            );
+            for (test, body) in clauses {
+                let condition = match test {
+                    Some(test) => {
+                        self.visit_expr(test);
+                        static_condition(self.db, self.file, test)
+                    }
+                    None => Some(true),
+                };
+                if condition != Some(false) {
+                    let narrowed = test.and_then(|test| self.handler_narrowing(test));
+                    self.visit_narrowed_body(body, narrowed);
+                }
+                if condition == Some(true) {
+                    break;
+                }
+            }
+        } else if let Stmt::Match(match_stmt) = stmt
+            && self.is_handler_name(&match_stmt.subject)
+        {
+            self.visit_expr(&match_stmt.subject);
+            for case in &match_stmt.cases {
+                if let Some(guard) = &case.guard {
+                    self.visit_expr(guard);
+                }
+                let narrowed = narrowed_by_pattern(self.db, self.file, &case.pattern);
+                self.visit_narrowed_body(&case.body, narrowed);
+            }
+        } else if let Stmt::AugAssign(aug_assign) = stmt {
+            // `a += b` falls back to `a = a + b` if there's no in-place method.
+            self.visit_dunder_call(
+                &aug_assign.target,
+                &[aug_assign.op.in_place_dunder(), aug_assign.op.dunder()],
+                aug_assign.range,
+            );
+            walk_stmt(self, stmt);
         } else {
             walk_stmt(self, stmt);
         }
     }
 
+    fn visit_comprehension(&mut self, comprehension: &'a Comprehension) {
+        self.visit_iteration(&comprehension.iter, comprehension.is_async);
+        walk_comprehension(self, comprehension);
+    }
+
+    fn visit_decorator(&mut self, decorator: &'a Decorator) {
+        // Applying a decorator calls it with the decorated function or class. The decorator a
+        // factory returns, e.g. for `@retry(3)`, can't be resolved, so only the factory call is
+        // visited, along with the rest of the expression.
+        if !decorator.expression.is_call_expr() {
+            self.visit_call(&decorator.expression, decorator.range);
+        }
+        walk_decorator(self, decorator);
+    }
+
     fn visit_expr(&mut self, expr: &'a Expr) {
-        if let Expr::Call(call) = expr {
-            let defs = definitions_for_call_func(self.db, self.file, *call.func.clone());
-            if let Some(defs) = defs {
-                for def in defs {
-                    if let ResolvedDefinition::Definition(def) = def {
-                        let definition_file = def.file(self.db);
-                        let definition_path = match definition_file.path(self.db) {
-                            ruff_db::files::FilePath::System(path) => path,
-                            ruff_db::files::FilePath::SystemVirtual(_) => continue,
-                            ruff_db::files::FilePath::Vendored(_) => continue,
-                        };
-                        if let Some(name) = def.name(self.db) {
-                            let key = (definition_path.as_str().into(), name);
-                            if self.call_stack.contains(&key) {
-                                continue;
-                            }
-                        }
-                        let transitive_errors = extract_errors(
-                            self.db,
-                            self.file,
-                            call.range,
-                            definition_file,
-                            def,
-                            self.target_exceptions.clone(),
-                            self.call_stack.clone(),
-                            self.exception_capture_stack.clone(),
-                        )
-                        .to_vec();
-                        self.errors.extend(
-                            transitive_errors
-                                .into_iter()
-                                .filter(|e| !self.exception_capture_stack.is_captured(e.name())),
-                        )
+        if let Expr::Lambda(lambda) = expr {
+            // The body of a lambda only runs when it's called.
+            if let Some(parameters) = &lambda.parameters {
+                self.visit_parameter_defaults(parameters);
+            }
+            return;
+        }
+        match expr {
+            Expr::Call(call) => {
+                self.visit_call(&call.func, call.range);
+                self.visit_callbacks(call);
+                self.visit_dunder_call(&call.func, &["__call__"], call.range);
+                if let Some(dunder) = builtin_dunder(self.db, self.file, &call.func)
+                    && let [argument] = &*call.arguments.args
+                {
+                    self.visit_dunder_call(argument, &[dunder], call.range);
+                }
+            }
+            Expr::BinOp(bin_op) => {
+                // `a + b` calls `b.__radd__(a)` if `a` doesn't define `__add__`.
+                let dunder = bin_op.op.dunder();
+                let reflected = instance_class(self.db, self.file, &bin_op.left)
+                    .is_none_or(|class| find_method(self.db, class, dunder).is_none())
+                    .then(|| instance_class(self.db, self.file, &bin_op.right))
+                    .flatten()
+                    .and_then(|class| find_method(self.db, class, bin_op.op.reflected_dunder()));
+                match reflected {
+                    Some((method_file, method_range)) => {
+                        self.visit_method(method_file, method_range, bin_op.range);
+                    }
+                    None => self.visit_dunder_call(&bin_op.left, &[dunder], bin_op.range),
+                }
+            }
+            Expr::UnaryOp(unary_op) => {
+                if let Some(dunder) = unary_dunder(unary_op.op) {
+                    self.visit_dunder_call(&unary_op.operand, &[dunder], unary_op.range);
+                }
+            }
+            Expr::Compare(compare) => {
+                let operands = std::iter::once(&*compare.left).chain(&compare.comparators);
+                for ((left, right), op) in operands.tuple_windows().zip(&compare.ops) {
+                    if let Some((dunder, reflected)) = compare_dunder(*op) {
+                        let receiver = if reflected { right } else { left };
+                        self.visit_dunder_call(receiver, &[dunder], compare.range);
                     }
                 }
             }
+            Expr::Subscript(subscript) => {
+                let dunder = match subscript.ctx {
+                    ExprContext::Store => "__setitem__",
+                    ExprContext::Del => "__delitem__",
+                    _ => "__getitem__",
+                };
+                self.visit_dunder_call(&subscript.value, &[dunder], subscript.range);
+            }
+            Expr::Attribute(attr) if attr.ctx == ExprContext::Load => {
+                if let Some(class_definition) = instance_class(self.db, self.file, &attr.value)
+                    && let Some((method_file, method_range)) =
+                        find_method(self.db, class_definition, attr.attr.as_str())
+                    && is_property(self.db, method_file, method_range)
+                {
+                    self.visit_method(method_file, method_range, attr.range);
+                }
+            }
+            _ => {}
         }
         walk_expr(self, expr);
     }
 }
 
+/// Whether `except exception:` catches any exception, so it isn't dead even if the `try` body
+/// raises nothing the analysis knows of.
+fn is_broad_catch(exception: &Exception) -> bool {
+    matches!(
+        exception.qualified_name.as_str(),
+        "builtins.Exception" | "builtins.BaseException"
+    )
+}
+
 fn definitions_for_call_func<'a>(
     db: &'a dyn Db,
     file: File,
-    func: Expr,
+    func: &Expr,
 ) -> Option<Vec<ResolvedDefinition<'a>>> {
-    if let Expr::Name(ref name) = func {
+    if let Expr::Name(name) = func {
         return Some(definitions_for_name(db, file, name));
-    } else if let Expr::Attribute(ref attr) = func {
+    } else if let Expr::Attribute(attr) = func {
         return Some(definitions_for_attribute(db, file, attr));
     }
     None
@@ -0,0 +1,50 @@
+import errors_a
+
+RETRYABLE_ERRORS = (TimeoutError, ConnectionError)
+
+
+def raises_error() -> None:
+    """Raise an error.
+
+    Raises:
+        errors_a.Error: Always.
+    """
+    raise errors_a.Error()
+
+
+def raises_timeout() -> None:
+    """Raise a timeout.
+
+    Raises:
+        TimeoutError: Always.
+    """
+    raise TimeoutError()
+
+
+def catches_attribute() -> None:
+    try:
+        raises_error()
+    except errors_a.Error:
+        pass
+
+
+def catches_attribute_tuple() -> None:
+    try:
+        raises_error()
+        raises_timeout()
+    except (errors_a.Error, TimeoutError):
+        pass
+
+
+def catches_tuple_constant() -> None:
+    try:
+        raises_timeout()
+    except RETRYABLE_ERRORS:
+        pass
+
+
+def catches_other_tuple_constant() -> None:
+    try:
+        raises_error()
+    except RETRYABLE_ERRORS:
+        pass
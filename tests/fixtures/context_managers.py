@@ -0,0 +1,77 @@
+from contextlib import suppress
+from typing import Literal
+
+
+class Resource:
+    def __enter__(self) -> "Resource":
+        """Opens the resource.
+
+        Raises:
+            ConnectionError: If the resource can't be reached.
+        """
+        raise ConnectionError()
+
+    def __exit__(self, *args: object) -> None:
+        """Closes the resource.
+
+        Raises:
+            TimeoutError: If closing the resource takes too long.
+        """
+        raise TimeoutError()
+
+
+class AsyncResource:
+    async def __aenter__(self) -> "AsyncResource":
+        """Opens the resource.
+
+        Raises:
+            PermissionError: If the resource can't be accessed.
+        """
+        raise PermissionError()
+
+    async def __aexit__(self, *args: object) -> None:
+        pass
+
+
+class Suppressing:
+    def __enter__(self) -> None:
+        pass
+
+    def __exit__(self, *args: object) -> bool:
+        return True
+
+
+class AlwaysSuppressing(Resource):
+    def __exit__(self, *args: object) -> Literal[True]:
+        return True
+
+
+def creates_resource() -> None:
+    Resource()
+
+
+def uses_resource() -> None:
+    with Resource():
+        raise ValueError()
+
+
+async def uses_async_resource() -> None:
+    async with AsyncResource():
+        pass
+
+
+def suppresses_everything() -> None:
+    with Suppressing():
+        raise ValueError()
+
+
+def suppresses_literal_true() -> None:
+    with AlwaysSuppressing():
+        raise ValueError()
+
+
+def suppresses_listed(mapping: dict[str, int]) -> None:
+    with suppress(KeyError):
+        if mapping:
+            raise KeyError()
+        raise IndexError()
@@ -0,0 +1,17 @@
+import json
+
+
+class FetchError(Exception):
+    pass
+
+
+def fetch(url: str) -> str:
+    raise RuntimeError(url)
+
+
+def fetches(url: str) -> str:
+    return fetch(url)
+
+
+def serializes(value: object) -> str:
+    return json.dumps(value)
@@ -0,0 +1,7 @@
+def has_extra_exceptions_documented() -> None:
+    """
+    Raises:
+        RuntimeError: Oopsie!
+        ValueError: Whoops!
+    """
+    raise RuntimeError()
@@ -0,0 +1,112 @@
+import fractions
+import json
+
+
+class ParseError(Exception):
+    pass
+
+
+class NetworkError(Exception):
+    pass
+
+
+class ConnectTimeout(NetworkError):
+    pass
+
+
+def parse(value: str) -> int:
+    """Parse a value.
+
+    Raises:
+        ParseError: If the value is invalid.
+    """
+    raise ParseError(value)
+
+
+def connect() -> None:
+    """Connect to the server.
+
+    Raises:
+        ConnectTimeout: If connecting times out.
+    """
+    raise ConnectTimeout()
+
+
+def stale_handler(value: str) -> int:
+    """Parse a value.
+
+    Raises:
+        ParseError: If the value is invalid.
+    """
+    try:
+        return parse(value)
+    except NetworkError:
+        return 0
+
+
+def live_handlers(value: str) -> int:
+    try:
+        connect()
+        return parse(value)
+    except NetworkError:
+        return 0
+    except ParseError:
+        return 1
+
+
+def partially_dead_tuple(value: str) -> int:
+    try:
+        return parse(value)
+    except (ParseError, NetworkError):
+        return 0
+
+
+def broad_handler() -> None:
+    try:
+        pass
+    except Exception:
+        pass
+
+
+def dead_tuple() -> None:
+    try:
+        pass
+    except (ParseError, NetworkError):
+        pass
+
+
+def builtin_call(value: str) -> int:
+    try:
+        return int(value)
+    except ValueError:
+        return 0
+
+
+def stdlib_call(value: str) -> object:
+    try:
+        return json.loads(value)
+    except json.JSONDecodeError:
+        return None
+
+
+def unknown_class_call(value: str) -> object:
+    try:
+        return fractions.Fraction(value)
+    except ValueError:
+        return None
+
+
+def retry(attempts: int):
+    def decorator(func):
+        return func
+
+    return decorator
+
+
+def decorated_in_try() -> None:
+    try:
+        @retry(3)
+        def task() -> None:
+            pass
+    except NetworkError:
+        pass
@@ -0,0 +1,73 @@
+from abc import ABC, abstractmethod
+from typing import Annotated
+
+
+def raises(*exceptions: type[BaseException]):
+    def decorator(func):
+        return func
+
+    return decorator
+
+
+class Raises:
+    def __class_getitem__(cls, item):
+        return cls
+
+
+class NotFound(Exception):
+    pass
+
+
+class Conflict(Exception):
+    pass
+
+
+@raises(NotFound)
+def finds(key: str) -> str:
+    raise NotFound(key)
+
+
+@raises(NotFound, Conflict)
+def updates(key: str) -> None:
+    raise Conflict(key)
+
+
+def fetches(key: str) -> Annotated[str, Raises[NotFound]]:
+    raise Conflict(key)
+
+
+@raises(Missing)
+def misdeclared(key: str) -> str:
+    return key
+
+
+@raises(NotFound)
+def remote(key: str) -> str: ...
+
+
+def calls_remote(key: str) -> str:
+    return remote(key)
+
+
+@raises(NotFound)
+def documented_only(key: str) -> str:
+    """Looks up a key."""
+
+
+class Store(ABC):
+    @abstractmethod
+    @raises(NotFound)
+    def get(self, key: str) -> str:
+        """Gets a value."""
+
+
+class Registry:
+    raises = dict
+
+
+registry = Registry()
+
+
+@registry.raises(NotFound)
+def registered(key: str) -> str:
+    raise NotFound(key)
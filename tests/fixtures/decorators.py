@@ -0,0 +1,65 @@
+import functools
+from typing import Any, Callable
+
+
+class ValidationError(Exception):
+    pass
+
+
+def validate(func: Callable[..., Any]) -> Callable[..., Any]:
+    @functools.wraps(func)
+    def wrapper(*args: Any, **kwargs: Any) -> Any:
+        """Validates the arguments before calling the function.
+
+        Raises:
+            ValidationError: If no arguments are given.
+        """
+        if not args:
+            raise ValidationError()
+        return func(*args, **kwargs)
+
+    return wrapper
+
+
+def swallow_timeouts(default: Any) -> Callable[[Callable[..., Any]], Callable[..., Any]]:
+    def decorator(func: Callable[..., Any]) -> Callable[..., Any]:
+        @functools.wraps(func)
+        def wrapper(*args: Any, **kwargs: Any) -> Any:
+            try:
+                return func(*args, **kwargs)
+            except TimeoutError:
+                return default
+
+        return wrapper
+
+    return decorator
+
+
+@validate
+def create(name: str) -> str:
+    """Creates an item.
+
+    Raises:
+        KeyError: If the item already exists.
+    """
+    raise KeyError()
+
+
+@swallow_timeouts(None)
+def fetch(url: str) -> str:
+    """Fetches a resource.
+
+    Raises:
+        ConnectionError: If the resource can't be reached.
+    """
+    if url:
+        raise ConnectionError()
+    raise TimeoutError()
+
+
+def calls_create() -> None:
+    create("name")
+
+
+def calls_fetch() -> None:
+    fetch("url")
@@ -0,0 +1,41 @@
+def multiple_exceptions_per_entry(i: int) -> None:
+    """Do something.
+
+    Raises:
+        ValueError, TypeError: If the input is invalid.
+        KeyError: See https://example.com for details.
+    """
+    if i > 0:
+        raise ValueError()
+    raise KeyError()
+
+
+def continuation_lines() -> None:
+    """Do something.
+
+    Raises:
+        RuntimeError: If something goes wrong, this description
+            is wrapped onto: a continuation line.
+        ValueError: Whoops!
+    """
+    raise RuntimeError()
+
+
+def numpy_multiple_exceptions() -> None:
+    """Do something.
+
+    Raises
+    ------
+    RuntimeError, ValueError
+        If something goes wrong.
+    """
+    raise RuntimeError()
+
+
+def sphinx_multiple_exceptions() -> None:
+    """Do something.
+
+    :raises RuntimeError, ValueError: If something goes wrong,
+        see https://example.com.
+    """
+    raise RuntimeError()
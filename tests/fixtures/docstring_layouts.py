@@ -0,0 +1,26 @@
+def prefixed_docstring() -> None:
+    r"""Do something with ü.
+
+    Raises:
+        RuntimeError: Oopsie!
+        ValueError: Whoops, ünïcode!
+    """
+    raise RuntimeError()
+
+
+def tab_indented() -> None:
+	"""Do something.
+
+	Raises:
+		ValueError: Whoops!
+	"""
+	pass
+
+
+def non_ascii_description() -> None:
+    """Do something.
+
+    :raises RuntimeError: über Oopsie!
+    :raises ÜberError: Whoops!
+    """
+    raise RuntimeError()
@@ -0,0 +1,27 @@
+def epytext_documented() -> None:
+    """Do something.
+
+    @raise RuntimeError: Oopsie!
+    @raise ValueError: Whoops!
+    """
+    raise RuntimeError()
+
+
+def raises_mentioned_in_prose() -> None:
+    """Do something.
+
+    The word Raises: in prose does not start a section.
+        RuntimeError: Not an entry.
+    """
+    raise RuntimeError()
+
+
+def raises_in_code_example() -> None:
+    """Do something.
+
+    ```
+    Raises:
+        RuntimeError: Not an entry.
+    ```
+    """
+    raise RuntimeError()
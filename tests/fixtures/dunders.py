@@ -0,0 +1,145 @@
+from typing import Iterator
+
+
+class Vector:
+    def __add__(self, other: "Vector") -> "Vector":
+        """Adds two vectors.
+
+        Raises:
+            ArithmeticError: If the vectors have different lengths.
+        """
+        raise ArithmeticError()
+
+    def __neg__(self) -> "Vector":
+        """Negates the vector.
+
+        Raises:
+            OverflowError: If a component can't be negated.
+        """
+        raise OverflowError()
+
+    def __lt__(self, other: "Vector") -> bool:
+        """Compares the lengths of two vectors.
+
+        Raises:
+            TypeError: If the vectors can't be compared.
+        """
+        raise TypeError()
+
+    def __len__(self) -> int:
+        """Returns the number of components.
+
+        Raises:
+            RuntimeError: If the vector was released.
+        """
+        raise RuntimeError()
+
+    def __call__(self) -> None:
+        """Normalizes the vector.
+
+        Raises:
+            ZeroDivisionError: If the vector has no length.
+        """
+        raise ZeroDivisionError()
+
+
+class Table:
+    def __getitem__(self, key: str) -> int:
+        """Looks up a row.
+
+        Raises:
+            KeyError: If there's no such row.
+        """
+        raise KeyError()
+
+    def __iter__(self) -> Iterator[int]:
+        """Iterates over the rows.
+
+        Raises:
+            PermissionError: If the table can't be read.
+        """
+        raise PermissionError()
+
+    @property
+    def size(self) -> int:
+        """Returns the number of rows.
+
+        Raises:
+            ConnectionError: If the table is remote and unreachable.
+        """
+        raise ConnectionError()
+
+
+def adds(a: Vector, b: Vector) -> Vector:
+    return a + b
+
+
+def adds_in_place(a: Vector, b: Vector) -> None:
+    a += b
+
+
+def negates(a: Vector) -> Vector:
+    return -a
+
+
+def compares(a: Vector, b: Vector) -> bool:
+    return a < b
+
+
+def measures(a: Vector) -> int:
+    return len(a)
+
+
+def normalizes(a: Vector) -> None:
+    a()
+
+
+def looks_up(table: Table) -> int:
+    return table["key"]
+
+
+def iterates(table: Table) -> None:
+    for _ in table:
+        pass
+
+
+def collects(table: Table) -> list[int]:
+    return [row for row in table]
+
+
+def sizes(table: Table) -> int:
+    return table.size
+
+
+class RowCursor:
+    def __next__(self) -> int:
+        """Fetches the next row.
+
+        Raises:
+            TimeoutError: If fetching times out.
+        """
+        raise TimeoutError()
+
+
+class Rows:
+    def __iter__(self) -> RowCursor:
+        return RowCursor()
+
+
+class Money:
+    def __radd__(self, other: int) -> "Money":
+        """Adds an amount to the money.
+
+        Raises:
+            ValueError: If the amount is negative.
+        """
+        raise ValueError()
+
+
+def iterates_cursor(rows: Rows) -> None:
+    for _ in rows:
+        pass
+
+
+def adds_reflected(amount: int, money: Money) -> Money:
+    return amount + money
@@ -0,0 +1,48 @@
+import asyncio
+
+
+async def fails(value: str) -> int:
+    """Fails.
+
+    Raises:
+        ValueError: Always.
+    """
+    raise ValueError(value)
+
+
+async def gathers(values: list[str]) -> None:
+    async with asyncio.TaskGroup() as group:
+        for value in values:
+            group.create_task(fails(value))
+
+
+async def documents_group(values: list[str]) -> None:
+    """Gathers values.
+
+    Raises:
+        ExceptionGroup[ValueError]: If a value is invalid.
+    """
+    async with asyncio.TaskGroup() as group:
+        for value in values:
+            group.create_task(fails(value))
+
+
+async def handles_group(values: list[str]) -> None:
+    try:
+        await gathers(values)
+    except* ValueError:
+        pass
+
+
+async def handles_other(values: list[str]) -> None:
+    try:
+        await gathers(values)
+    except* KeyError:
+        pass
+
+
+async def reraises(values: list[str]) -> None:
+    try:
+        await fails(values[0])
+    except* ValueError:
+        raise
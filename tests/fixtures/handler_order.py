@@ -0,0 +1,50 @@
+class NotFound(Exception):
+    pass
+
+
+def lookup(key: str) -> str:
+    """Look up a key.
+
+    Raises:
+        NotFound: If the key is missing.
+        KeyError: If the key is invalid.
+    """
+    if key == "missing":
+        raise NotFound(key)
+    raise KeyError(key)
+
+
+def shadowed_handler(key: str) -> str:
+    try:
+        return lookup(key)
+    except Exception:
+        return ""
+    except NotFound:
+        raise
+
+
+def first_handler_wins(key: str) -> str:
+    try:
+        return lookup(key)
+    except NotFound:
+        return ""
+    except:
+        raise
+
+
+def partially_shadowed(key: str) -> str:
+    try:
+        return lookup(key)
+    except LookupError:
+        return ""
+    except (KeyError, NotFound):
+        raise
+
+
+def reraised_by_broad_handler(key: str) -> str:
+    try:
+        return lookup(key)
+    except NotFound:
+        return ""
+    except Exception:
+        raise
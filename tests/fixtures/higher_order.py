@@ -0,0 +1,60 @@
+from typing import Callable
+
+
+def parse(value: str) -> int:
+    """Parses a value.
+
+    Raises:
+        ValueError: If the value isn't a number.
+    """
+    raise ValueError()
+
+
+def validate(value: int) -> int:
+    """Validates a value.
+
+    Raises:
+        OverflowError: If the value is too large.
+    """
+    raise OverflowError()
+
+
+def save() -> None:
+    """Saves the changes.
+
+    Raises:
+        PermissionError: If saving isn't allowed.
+    """
+    raise PermissionError()
+
+
+def run_in_tx(action: Callable[[], None]) -> None:
+    action()
+
+
+def register(action: Callable[[], None]) -> None:
+    pass
+
+
+def maps_items(items: list[str]) -> list[int]:
+    return list(map(parse, items))
+
+
+def sorts_items(items: list[int]) -> list[int]:
+    return sorted(items, key=validate)
+
+
+def runs_lambda() -> None:
+    run_in_tx(lambda: save())
+
+
+def runs_keyword() -> None:
+    run_in_tx(action=save)
+
+
+def registers_callback() -> None:
+    register(save)
+
+
+def picks_callback() -> Callable[[], None]:
+    return max(save, register, key=id)
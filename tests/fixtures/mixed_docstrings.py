@@ -0,0 +1,9 @@
+def mixed_raises_sections() -> None:
+    """Do something.
+
+    Raises:
+        ValueError: Whoops!
+
+    :raises RuntimeError: Oopsie!
+    """
+    raise RuntimeError()
@@ -0,0 +1,93 @@
+class Timeout(Exception):
+    pass
+
+
+class Conflict(Exception):
+    pass
+
+
+def request(key: str) -> None:
+    """Send a request.
+
+    Raises:
+        Timeout: If the request times out.
+        Conflict: If the key is taken.
+        KeyError: If the key is unknown.
+    """
+    if key == "timeout":
+        raise Timeout()
+    if key == "conflict":
+        raise Conflict()
+    raise KeyError(key)
+
+
+def log(error: Exception) -> None:
+    pass
+
+
+def reraises_narrowed(key: str) -> None:
+    try:
+        request(key)
+    except Exception as e:
+        if isinstance(e, (Timeout, Conflict)):
+            raise
+        log(e)
+
+
+def reraises_type_checked(key: str) -> None:
+    try:
+        request(key)
+    except Exception as e:
+        if type(e) is Timeout:
+            raise e
+        log(e)
+
+
+def reraises_matched(key: str) -> None:
+    try:
+        request(key)
+    except Exception as e:
+        match e:
+            case KeyError():
+                raise
+            case _:
+                log(e)
+
+
+def reraises_everything(key: str) -> None:
+    try:
+        request(key)
+    except Exception:
+        raise
+
+
+class RetryableTimeout(Timeout):
+    pass
+
+
+def fetch(key: str) -> None:
+    """Fetch a value.
+
+    Raises:
+        RetryableTimeout: If the request times out.
+    """
+    raise RetryableTimeout()
+
+
+def reraises_exact_type(key: str) -> None:
+    try:
+        request(key)
+        fetch(key)
+    except Exception as e:
+        if type(e) is Timeout:
+            raise
+        log(e)
+
+
+def reraises_unanalyzed(key: str) -> None:
+    try:
+        int(key)
+    except Exception as e:
+        if isinstance(e, Timeout):
+            raise
+        log(e)
@@ -0,0 +1,34 @@
+from typing import Callable
+
+
+def defines_closure() -> Callable[[], None]:
+    def helper() -> None:
+        raise ValueError()
+
+    return helper
+
+
+def calls_closure() -> None:
+    def helper() -> None:
+        raise ValueError()
+
+    helper()
+
+
+def defines_lambda() -> Callable[[], None]:
+    return lambda: calls_closure()
+
+
+def applies_inline_decorator() -> None:
+    def decorator(func: Callable[[], None]) -> Callable[[], None]:
+        raise ValueError()
+
+    @decorator
+    def decorated() -> None:
+        pass
+
+
+def defines_class() -> None:
+    class Inner:
+        def method(self) -> None:
+            raise ValueError()
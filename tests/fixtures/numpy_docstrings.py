@@ -0,0 +1,34 @@
+def has_all_exceptions_documented() -> None:
+    """Do something.
+
+    Raises
+    ------
+    RuntimeError
+        Oopsie!
+    """
+    raise RuntimeError()
+
+
+def has_extra_exceptions_documented() -> None:
+    """Do something.
+
+    Raises
+    ------
+    RuntimeError
+        Oopsie!
+    ValueError
+        Whoops!
+    """
+    raise ValueError()
+
+
+class MyCls:
+    def has_undocumented_exception(self) -> None:
+        """Do something.
+
+        Raises
+        ------
+        ValueError
+            Whoops!
+        """
+        raise RuntimeError()
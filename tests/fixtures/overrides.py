@@ -0,0 +1,123 @@
+class Plugin:
+    def __init__(self) -> None:
+        """Creates the plugin."""
+
+    def run(self) -> None:
+        """Runs the plugin.
+
+        Raises:
+            LookupError: If a resource is missing.
+        """
+        raise LookupError()
+
+    def stop(self) -> None:
+        pass
+
+
+class FilePlugin(Plugin):
+    def __init__(self) -> None:
+        """Creates the plugin.
+
+        Raises:
+            OSError: If the working directory is missing.
+        """
+        raise OSError()
+
+    def run(self) -> None:
+        """Runs the plugin on files.
+
+        Raises:
+            KeyError: If a file is missing.
+            PermissionError: If a file can't be read.
+        """
+        if self:
+            raise KeyError()
+        raise PermissionError()
+
+    def stop(self) -> None:
+        """Stops the plugin.
+
+        Raises:
+            ValueError: If the plugin isn't running.
+        """
+        raise ValueError()
+
+
+class CachedFilePlugin(FilePlugin):
+    def stop(self) -> None:
+        """Stops the plugin and clears its cache.
+
+        Raises:
+            TimeoutError: If the cache can't be cleared in time.
+        """
+        raise TimeoutError()
+
+
+class Storage:
+    def save(self) -> None:
+        """Saves the data.
+
+        Raises:
+            OSError: If writing fails.
+        """
+        raise OSError()
+
+
+class RemoteStorage(Storage):
+    pass
+
+
+class CachedStorage(Storage):
+    def save(self) -> None:
+        """Saves the data to the cache.
+
+        Raises:
+            MemoryError: If the cache is full.
+        """
+        raise MemoryError()
+
+
+class CachedRemoteStorage(RemoteStorage, CachedStorage):
+    def save(self) -> None:
+        """Saves the data to the cache before uploading it.
+
+        Raises:
+            MemoryError: If the cache is full.
+        """
+        raise MemoryError()
+
+
+def raises(*exceptions: type[BaseException]):
+    def decorator(func):
+        return func
+
+    return decorator
+
+
+class Source:
+    def read(self) -> bytes:
+        """Reads the data."""
+        return b""
+
+    @raises(ConnectionError)
+    def fetch(self) -> bytes:
+        """Fetches the data."""
+        raise ConnectionError()
+
+
+class HttpSource(Source):
+    def read(self) -> bytes:
+        """Reads the data over HTTP.
+
+        Raises:
+            ConnectionError: If the connection fails.
+        """
+        raise ConnectionError()
+
+    def fetch(self) -> bytes:
+        """Fetches the data over HTTP.
+
+        Raises:
+            TimeoutError: If the request times out.
+        """
+        raise TimeoutError()
@@ -0,0 +1,50 @@
+import errors_a
+import errors_b
+from errors_a import Error
+
+
+def documents_qualified_name() -> None:
+    """Do something.
+
+    Raises:
+        errors_a.Error: Oopsie!
+    """
+    raise Error()
+
+
+def documents_same_name_from_other_module() -> None:
+    """Do something.
+
+    Raises:
+        errors_b.Error: Oopsie!
+    """
+    raise errors_a.Error()
+
+
+def documents_unresolved_error() -> None:
+    """Do something.
+
+    Raises:
+        NotAnError: Oopsie!
+        Error: Oopsie!
+    """
+    raise Error()
+
+
+def documents_fully_qualified_name() -> None:
+    """Do something.
+
+    Raises:
+        json.JSONDecodeError: Oopsie!
+    """
+
+
+def documents_locally_imported_name() -> None:
+    """Do something.
+
+    Raises:
+        LocalError: Oopsie!
+    """
+    from errors_b import Error as LocalError
+
+    raise LocalError()
@@ -0,0 +1,34 @@
+class MyError(Exception):
+    pass
+
+
+def raises_class() -> None:
+    raise ValueError
+
+
+def raises_instance_variable() -> None:
+    err = ValueError()
+    raise err
+
+
+def raises_with_traceback() -> None:
+    raise MyError().with_traceback(None)
+
+
+class Raiser:
+    error: MyError
+
+    def raises_attribute(self) -> None:
+        raise self.error
+
+
+def raises_union(flag: bool) -> None:
+    err = ValueError() if flag else MyError()
+    raise err
+
+
+def raises_other_name_in_handler() -> None:
+    try:
+        pass
+    except MyError:
+        raise ValueError
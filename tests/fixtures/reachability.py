@@ -0,0 +1,70 @@
+import sys
+from typing import TYPE_CHECKING, NoReturn
+
+
+def fail(message: str) -> NoReturn:
+    """Fail.
+
+    Raises:
+        RuntimeError: Always.
+    """
+    raise RuntimeError(message)
+
+
+def type_checking_only() -> None:
+    if TYPE_CHECKING:
+        raise TypeError()
+
+
+def old_versions_only() -> None:
+    if sys.version_info < (3, 8):
+        raise ValueError()
+
+
+def after_return() -> int:
+    return 1
+    raise ValueError()
+
+
+def after_no_return() -> None:
+    fail("unreachable")
+    raise ValueError()
+
+
+def runtime_only() -> None:
+    if not TYPE_CHECKING:
+        raise KeyError()
+    else:
+        raise TypeError()
+
+
+def reachable_else() -> None:
+    if sys.version_info < (3, 8):
+        raise ValueError()
+    else:
+        raise KeyError()
+
+
+class Settings:
+    TYPE_CHECKING = True
+
+
+def settings_flag(settings: Settings) -> None:
+    if settings.TYPE_CHECKING:
+        raise IndexError()
+
+
+def local_flag() -> None:
+    TYPE_CHECKING = True
+    if TYPE_CHECKING:
+        raise LookupError()
+
+
+def isinstance_guard(value: int) -> None:
+    if not isinstance(value, int):
+        raise TypeError()
+
+
+def none_guard(value: str) -> None:
+    if value is None:
+        raise ValueError()
@@ -0,0 +1,67 @@
+def build_cause() -> Exception:
+    """Build a cause.
+
+    Raises:
+        KeyError: Always.
+    """
+    raise KeyError()
+
+
+def loses_context(value: str) -> int:
+    """Parse a value.
+
+    Raises:
+        RuntimeError: If the value is invalid.
+    """
+    try:
+        return int(value)
+    except ValueError:
+        raise RuntimeError(value)
+
+
+def chains(value: str) -> int:
+    """Parse a value.
+
+    Raises:
+        RuntimeError: If the value is invalid.
+    """
+    try:
+        return int(value)
+    except ValueError as exc:
+        raise RuntimeError(value) from exc
+
+
+def suppresses_context(value: str) -> int:
+    """Parse a value.
+
+    Raises:
+        RuntimeError: If the value is invalid.
+    """
+    try:
+        return int(value)
+    except ValueError:
+        raise RuntimeError(value) from None
+
+
+def reraises(value: str) -> int:
+    """Parse a value.
+
+    Raises:
+        ValueError: If the value is invalid.
+    """
+    try:
+        return int(value)
+    except ValueError as exc:
+        raise exc
+
+
+def analyzes_cause(value: str) -> int:
+    """Parse a value.
+
+    Raises:
+        RuntimeError: If the value is invalid.
+    """
+    try:
+        return int(value)
+    except ValueError:
+        raise RuntimeError(value) from build_cause()
@@ -0,0 +1,28 @@
+def has_all_exceptions_documented(key: str) -> None:
+    """Do something.
+
+    :param key: The key.
+    :raises RuntimeError: Oopsie!
+    :raise KeyError:
+    """
+    if key:
+        raise KeyError()
+    raise RuntimeError()
+
+
+def has_extra_exceptions_documented() -> None:
+    """Do something.
+
+    :raises RuntimeError: Oopsie!
+    :raises ValueError: Whoops!
+    """
+    raise ValueError()
+
+
+class MyCls:
+    def has_undocumented_exception(self) -> None:
+        """Do something.
+
+        :raises ValueError: Whoops!
+        """
+        raise RuntimeError()
@@ -0,0 +1,28 @@
+import json
+
+
+def parses_number(value: str) -> int:
+    return int(value)
+
+
+def parses_json(value: str) -> object:
+    return json.loads(value)
+
+
+def looks_up(mapping: dict[str, int], key: str) -> int:
+    return mapping[key]
+
+
+def pops(items: list[int]) -> int:
+    return items.pop()
+
+
+def divides(a: int, b: int) -> float:
+    return a / b
+
+
+def handles_errors(value: str) -> int:
+    try:
+        return int(value)
+    except ValueError:
+        return 0
@@ -0,0 +1,49 @@
+from typing import Protocol
+
+
+class Exporter(Protocol):
+    def export(self) -> None:
+        """Exports the data."""
+        ...
+
+
+class CsvExporter:
+    def export(self) -> None:
+        """Exports the data as CSV.
+
+        Raises:
+            UnicodeError: If a value can't be encoded.
+        """
+        raise UnicodeError()
+
+
+def exports(exporter: Exporter) -> None:
+    exporter.export()
+
+
+class InvalidJson(ValueError):
+    pass
+
+
+class Handler:
+    def process(self) -> None:
+        """Processes a request.
+
+        Raises:
+            ValueError: If the request is invalid.
+        """
+        raise ValueError()
+
+
+class JsonHandler(Handler):
+    def process(self) -> None:
+        """Processes a JSON request.
+
+        Raises:
+            InvalidJson: If the JSON is malformed.
+        """
+        raise InvalidJson()
+
+
+def handles_unbound(handler: type[Handler]) -> None:
+    handler.process(handler())
@@ -0,0 +1,27 @@
+class StorageError(Exception): ...
+
+
+class StorageTimeout(StorageError): ...
+
+
+class StorageNotFound(StorageError): ...
+
+
+def documents_superclass(i: int) -> None:
+    """Do something.
+
+    Raises:
+        StorageError: If the storage fails.
+    """
+    if i > 0:
+        raise StorageTimeout()
+    raise StorageNotFound()
+
+
+def documents_subclass() -> None:
+    """Do something.
+
+    Raises:
+        StorageTimeout: If the storage times out.
+    """
+    raise StorageError()
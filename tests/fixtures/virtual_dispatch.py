@@ -0,0 +1,32 @@
+class BaseHandler:
+    def process(self) -> None:
+        """Processes a request.
+
+        Raises:
+            ValueError: If the request is invalid.
+        """
+        raise ValueError()
+
+
+class JsonHandler(BaseHandler):
+    def process(self) -> None:
+        """Processes a JSON request.
+
+        Raises:
+            KeyError: If a field is missing.
+        """
+        raise KeyError()
+
+
+class StrictJsonHandler(JsonHandler):
+    def process(self) -> None:
+        """Processes a JSON request, rejecting unknown fields.
+
+        Raises:
+            PermissionError: If a field isn't allowed.
+        """
+        raise PermissionError()
+
+
+def handle(handler: BaseHandler) -> None:
+    handler.process()
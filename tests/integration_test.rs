@@ -3,7 +3,10 @@ use ruff_source_file::LineIndex;
 use std::env::current_dir;
 
 use itertools::{EitherOrBoth, Itertools};
-use py_checked_exceptions::{analyze_project, resolve_absolute_module_path};
+use py_checked_exceptions::{
+    ContractFormat, DocstringStyle, ExceptionContracts, Settings, analyze_project,
+    resolve_absolute_module_path,
+};
 use ruff_db::{
     diagnostic::Diagnostic,
     files::{File, FilePath},
@@ -95,7 +98,7 @@ fn test_inheritance() -> Result<()> {
                 (26, 32),
             ),
             (
-                "Raises undocumented error CustomBaseError",
+                "Raises undocumented error CustomValueError",
                 (60, 9),
                 (60, 14),
             ),
@@ -123,28 +126,950 @@ fn test_docstrings() -> Result<()> {
     )
 }
 
+#[test]
+fn test_numpy_docstrings() -> Result<()> {
+    assert_diagnostics(
+        "numpy_docstrings.py",
+        None,
+        vec![
+            (
+                "Documents extra error that is never raised RuntimeError",
+                (17, 5),
+                (17, 17),
+            ),
+            ("Raises undocumented error RuntimeError", (34, 9), (34, 29)),
+            (
+                "Documents extra error that is never raised ValueError",
+                (31, 9),
+                (31, 19),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_sphinx_docstrings() -> Result<()> {
+    assert_diagnostics(
+        "sphinx_docstrings.py",
+        None,
+        vec![
+            (
+                "Documents extra error that is never raised RuntimeError",
+                (16, 13),
+                (16, 25),
+            ),
+            ("Raises undocumented error RuntimeError", (28, 9), (28, 29)),
+            (
+                "Documents extra error that is never raised ValueError",
+                (26, 17),
+                (26, 27),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_docstring_styles() -> Result<()> {
+    assert_diagnostics(
+        "docstring_styles.py",
+        None,
+        vec![
+            (
+                "Documents extra error that is never raised ValueError",
+                (5, 12),
+                (5, 22),
+            ),
+            ("Raises undocumented error RuntimeError", (16, 5), (16, 25)),
+            ("Raises undocumented error RuntimeError", (27, 5), (27, 25)),
+        ],
+    )
+}
+
+#[test]
+fn test_mixed_docstrings() -> Result<()> {
+    assert_diagnostics(
+        "mixed_docstrings.py",
+        None,
+        vec![
+            ("Docstring mixes Google and Sphinx styles", (2, 5), (8, 8)),
+            ("Raises undocumented error RuntimeError", (9, 5), (9, 25)),
+            (
+                "Documents extra error that is never raised ValueError",
+                (5, 9),
+                (5, 19),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_mixed_docstrings_preferred_style() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "mixed_docstrings.py",
+        None,
+        Settings {
+            docstring_style: DocstringStyle::Sphinx,
+            ..Settings::default()
+        },
+        vec![("Docstring mixes Google and Sphinx styles", (2, 5), (8, 8))],
+    )
+}
+
+#[test]
+fn test_docstring_layouts() -> Result<()> {
+    assert_diagnostics(
+        "docstring_layouts.py",
+        None,
+        vec![
+            (
+                "Documents extra error that is never raised ValueError",
+                (6, 9),
+                (6, 19),
+            ),
+            (
+                "Documents extra error that is never raised ValueError",
+                (15, 3),
+                (15, 13),
+            ),
+            (
+                "Documented error ÜberError does not resolve to an exception class",
+                (24, 13),
+                (24, 22),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_crlf_docstrings() -> Result<()> {
+    assert_diagnostics(
+        "crlf_docstrings.py",
+        None,
+        vec![(
+            "Documents extra error that is never raised ValueError",
+            (5, 9),
+            (5, 19),
+        )],
+    )
+}
+
+#[test]
+fn test_docstring_entries() -> Result<()> {
+    assert_diagnostics(
+        "docstring_entries.py",
+        None,
+        vec![
+            (
+                "Documents extra error that is never raised TypeError",
+                (5, 21),
+                (5, 30),
+            ),
+            (
+                "Documents extra error that is never raised ValueError",
+                (19, 9),
+                (19, 19),
+            ),
+            (
+                "Documents extra error that is never raised ValueError",
+                (29, 19),
+                (29, 29),
+            ),
+            (
+                "Documents extra error that is never raised ValueError",
+                (38, 27),
+                (38, 37),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_qualified_docstrings() -> Result<()> {
+    assert_diagnostics(
+        "qualified_docstrings.py",
+        None,
+        vec![
+            ("Raises undocumented error Error", (21, 5), (21, 27)),
+            (
+                "Documents extra error that is never raised errors_b.Error",
+                (19, 9),
+                (19, 23),
+            ),
+            (
+                "Documented error NotAnError does not resolve to an exception class",
+                (28, 9),
+                (28, 19),
+            ),
+            (
+                "Documents extra error that is never raised json.JSONDecodeError",
+                (38, 9),
+                (38, 29),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_superclass_docs() -> Result<()> {
+    assert_diagnostics(
+        "superclass_docs.py",
+        None,
+        vec![
+            (
+                "Raises undocumented error StorageNotFound",
+                (18, 5),
+                (18, 28),
+            ),
+            (
+                "Raises undocumented error StorageTimeout",
+                (17, 9),
+                (17, 31),
+            ),
+            (
+                "Documents extra error that is never raised StorageError",
+                (14, 9),
+                (14, 21),
+            ),
+            ("Raises undocumented error StorageError", (27, 5), (27, 25)),
+            (
+                "Documents extra error that is never raised StorageTimeout",
+                (25, 9),
+                (25, 23),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_superclass_docs_allowed() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "superclass_docs.py",
+        None,
+        Settings {
+            allow_documented_superclasses: true,
+            ..Settings::default()
+        },
+        vec![
+            ("Raises undocumented error StorageError", (27, 5), (27, 25)),
+            (
+                "Documents extra error that is never raised StorageTimeout",
+                (25, 9),
+                (25, 23),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_raise_expressions() -> Result<()> {
+    assert_diagnostics(
+        "raise_expressions.py",
+        None,
+        vec![
+            ("Raises undocumented error ValueError", (6, 5), (6, 21)),
+            ("Raises undocumented error ValueError", (11, 5), (11, 14)),
+            ("Raises undocumented error MyError", (15, 5), (15, 41)),
+            ("Raises undocumented error MyError", (22, 9), (22, 25)),
+            ("Raises undocumented error ValueError", (27, 5), (27, 14)),
+            ("Raises undocumented error MyError", (27, 5), (27, 14)),
+            ("Raises undocumented error ValueError", (34, 9), (34, 25)),
+        ],
+    )
+}
+
+#[test]
+fn test_nested_scopes() -> Result<()> {
+    assert_diagnostics(
+        "nested_scopes.py",
+        None,
+        vec![
+            ("Raises undocumented error ValueError", (6, 9), (6, 27)),
+            ("Raises undocumented error ValueError", (15, 5), (15, 13)),
+            ("Raises undocumented error ValueError", (13, 9), (13, 27)),
+            ("Raises undocumented error ValueError", (26, 5), (26, 15)),
+            ("Raises undocumented error ValueError", (24, 9), (24, 27)),
+            ("Raises undocumented error ValueError", (34, 13), (34, 31)),
+        ],
+    )
+}
+
+#[test]
+fn test_context_managers() -> Result<()> {
+    assert_diagnostics(
+        "context_managers.py",
+        None,
+        vec![
+            (
+                "Raises undocumented error ConnectionError",
+                (54, 10),
+                (54, 20),
+            ),
+            ("Raises undocumented error TimeoutError", (54, 10), (54, 20)),
+            ("Raises undocumented error ValueError", (55, 9), (55, 27)),
+            (
+                "Raises undocumented error PermissionError",
+                (59, 16),
+                (59, 31),
+            ),
+            (
+                "Raises undocumented error ConnectionError",
+                (69, 10),
+                (69, 29),
+            ),
+            ("Raises undocumented error IndexError", (77, 9), (77, 27)),
+        ],
+    )
+}
+
+#[test]
+fn test_higher_order() -> Result<()> {
+    assert_diagnostics(
+        "higher_order.py",
+        None,
+        vec![
+            ("Raises undocumented error ValueError", (40, 17), (40, 34)),
+            (
+                "Raises undocumented error OverflowError",
+                (44, 12),
+                (44, 39),
+            ),
+            (
+                "Raises undocumented error PermissionError",
+                (48, 5),
+                (48, 30),
+            ),
+            (
+                "Raises undocumented error PermissionError",
+                (52, 5),
+                (52, 27),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_decorators() -> Result<()> {
+    assert_diagnostics(
+        "decorators.py",
+        None,
+        vec![
+            (
+                "Raises undocumented error ValidationError",
+                (38, 1),
+                (38, 10),
+            ),
+            ("Raises undocumented error KeyError", (61, 5), (61, 19)),
+            (
+                "Raises undocumented error ValidationError",
+                (61, 5),
+                (61, 19),
+            ),
+            (
+                "Raises undocumented error ConnectionError",
+                (65, 5),
+                (65, 17),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_virtual_dispatch() -> Result<()> {
+    assert_diagnostics(
+        "virtual_dispatch.py",
+        None,
+        vec![
+            (
+                "Raises error KeyError not documented by the overridden method",
+                (18, 9),
+                (18, 25),
+            ),
+            (
+                "Raises error PermissionError not documented by the overridden method",
+                (28, 9),
+                (28, 32),
+            ),
+            ("Raises undocumented error ValueError", (32, 5), (32, 22)),
+        ],
+    )
+}
+
+#[test]
+fn test_virtual_dispatch_enabled() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "virtual_dispatch.py",
+        None,
+        Settings {
+            virtual_dispatch: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Raises error KeyError not documented by the overridden method",
+                (18, 9),
+                (18, 25),
+            ),
+            (
+                "Raises error PermissionError not documented by the overridden method",
+                (28, 9),
+                (28, 32),
+            ),
+            ("Raises undocumented error KeyError", (32, 5), (32, 22)),
+            (
+                "Raises undocumented error PermissionError",
+                (32, 5),
+                (32, 22),
+            ),
+            ("Raises undocumented error ValueError", (32, 5), (32, 22)),
+        ],
+    )
+}
+
+#[test]
+fn test_structural_dispatch() -> Result<()> {
+    assert_diagnostics(
+        "structural_dispatch.py",
+        None,
+        vec![("Raises undocumented error ValueError", (49, 5), (49, 31))],
+    )
+}
+
+#[test]
+fn test_structural_dispatch_enabled() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "structural_dispatch.py",
+        None,
+        Settings {
+            virtual_dispatch: true,
+            ..Settings::default()
+        },
+        vec![
+            ("Raises undocumented error UnicodeError", (21, 5), (21, 22)),
+            ("Raises undocumented error ValueError", (49, 5), (49, 31)),
+        ],
+    )
+}
+
+#[test]
+fn test_virtual_dispatch_names_override() -> Result<()> {
+    let settings = Settings {
+        virtual_dispatch: true,
+        ..Settings::default()
+    };
+    let diagnostics = analyze_project(fixture_db("virtual_dispatch.py")?, vec![], settings, None)?
+        .map(|diagnostic| format!("{diagnostic:?}"))
+        .collect::<Vec<_>>();
+    let dispatched = |message: &str, method: &str| {
+        diagnostics
+            .iter()
+            .any(|d| d.contains(message) && d.contains(&format!("Dispatched to {method}")))
+    };
+    assert!(dispatched(
+        "Raises undocumented error KeyError",
+        "JsonHandler.process"
+    ));
+    assert!(dispatched(
+        "Raises undocumented error PermissionError",
+        "StrictJsonHandler.process"
+    ));
+    assert!(!dispatched("Raises undocumented error ValueError", ""));
+    Ok(())
+}
+
+#[test]
+fn test_overrides() -> Result<()> {
+    assert_diagnostics(
+        "overrides.py",
+        None,
+        vec![
+            (
+                "Raises error PermissionError not documented by the overridden method",
+                (35, 9),
+                (35, 32),
+            ),
+            (
+                "Raises error TimeoutError not documented by the overridden method",
+                (53, 9),
+                (53, 29),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_overrides_declarations() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "overrides.py",
+        None,
+        Settings {
+            exception_declarations: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Raises error PermissionError not documented by the overridden method",
+                (35, 9),
+                (35, 32),
+            ),
+            (
+                "Raises error TimeoutError not documented by the overridden method",
+                (53, 9),
+                (53, 29),
+            ),
+            (
+                "Raises error TimeoutError not documented by the overridden method",
+                (123, 9),
+                (123, 29),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_dunders() -> Result<()> {
+    assert_diagnostics(
+        "dunders.py",
+        None,
+        vec![
+            (
+                "Raises undocumented error ArithmeticError",
+                (74, 12),
+                (74, 17),
+            ),
+            (
+                "Raises undocumented error ArithmeticError",
+                (78, 5),
+                (78, 11),
+            ),
+            (
+                "Raises undocumented error OverflowError",
+                (82, 12),
+                (82, 14),
+            ),
+            ("Raises undocumented error TypeError", (86, 12), (86, 17)),
+            ("Raises undocumented error RuntimeError", (90, 12), (90, 18)),
+            (
+                "Raises undocumented error ZeroDivisionError",
+                (94, 5),
+                (94, 8),
+            ),
+            ("Raises undocumented error KeyError", (98, 12), (98, 24)),
+            (
+                "Raises undocumented error PermissionError",
+                (102, 14),
+                (102, 19),
+            ),
+            (
+                "Raises undocumented error PermissionError",
+                (107, 28),
+                (107, 33),
+            ),
+            (
+                "Raises undocumented error ConnectionError",
+                (111, 12),
+                (111, 22),
+            ),
+            (
+                "Raises undocumented error TimeoutError",
+                (140, 14),
+                (140, 18),
+            ),
+            ("Raises undocumented error ValueError", (145, 12), (145, 26)),
+        ],
+    )
+}
+
+#[test]
+fn test_stdlib() -> Result<()> {
+    assert_diagnostics("stdlib.py", None, vec![])
+}
+
+#[test]
+fn test_stdlib_exceptions() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "stdlib.py",
+        None,
+        Settings {
+            stdlib_exceptions: true,
+            ..Settings::default()
+        },
+        vec![
+            ("Raises undocumented error ValueError", (5, 12), (5, 22)),
+            (
+                "Raises undocumented error JSONDecodeError",
+                (9, 12),
+                (9, 29),
+            ),
+            ("Raises undocumented error KeyError", (13, 12), (13, 24)),
+            ("Raises undocumented error IndexError", (17, 12), (17, 23)),
+            (
+                "Raises undocumented error ZeroDivisionError",
+                (21, 12),
+                (21, 17),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_stdlib_exceptions_target() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "stdlib.py",
+        Some("builtins.LookupError".into()),
+        Settings {
+            stdlib_exceptions: true,
+            ..Settings::default()
+        },
+        vec![
+            ("Raises undocumented error KeyError", (13, 12), (13, 24)),
+            ("Raises undocumented error IndexError", (17, 12), (17, 23)),
+        ],
+    )
+}
+
+#[test]
+fn test_contracts() -> Result<()> {
+    let contracts = ExceptionContracts::parse(
+        r#"
+        [raises]
+        "contracts.fetch" = ["contracts.FetchError"]
+        "json.dumps" = ["builtins.TypeError"]
+        "#,
+        ContractFormat::Toml,
+    )?;
+    assert_diagnostics_with_settings(
+        "contracts.py",
+        None,
+        Settings {
+            contracts,
+            ..Settings::default()
+        },
+        vec![
+            ("Raises undocumented error RuntimeError", (9, 5), (9, 28)),
+            ("Raises undocumented error FetchError", (13, 12), (13, 22)),
+            ("Raises undocumented error TypeError", (17, 12), (17, 29)),
+        ],
+    )
+}
+
+#[test]
+fn test_unresolved_contracts() -> Result<()> {
+    let contracts = ExceptionContracts::parse(
+        r#"
+        [raises]
+        "contracts.fetch" = ["contracts.FetchError", "contracts.FetchErorr"]
+        "json.dumps" = ["builtins.TypeError", "builtins.fetch"]
+        "#,
+        ContractFormat::Toml,
+    )?;
+    let error = contracts
+        .validate(&fixture_db("contracts.py")?)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The exception contracts list names that don't resolve to exception classes: \
+         contracts.FetchErorr, builtins.fetch"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_declarations() -> Result<()> {
+    assert_diagnostics(
+        "declarations.py",
+        None,
+        vec![
+            ("Raises undocumented error NotFound", (27, 5), (27, 24)),
+            ("Raises undocumented error Conflict", (32, 5), (32, 24)),
+            ("Raises undocumented error Conflict", (36, 5), (36, 24)),
+            ("Raises undocumented error NotFound", (73, 5), (73, 24)),
+        ],
+    )
+}
+
+#[test]
+fn test_declarations_enabled() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "declarations.py",
+        None,
+        Settings {
+            exception_declarations: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Documents extra error that is never raised NotFound",
+                (30, 9),
+                (30, 17),
+            ),
+            ("Raises undocumented error Conflict", (36, 5), (36, 24)),
+            (
+                "Documents extra error that is never raised NotFound",
+                (35, 48),
+                (35, 56),
+            ),
+            (
+                "Declared error Missing does not resolve to an exception class",
+                (39, 9),
+                (39, 16),
+            ),
+            ("Raises undocumented error NotFound", (49, 12), (49, 23)),
+            (
+                "Documents extra error that is never raised NotFound",
+                (52, 9),
+                (52, 17),
+            ),
+            ("Raises undocumented error NotFound", (73, 5), (73, 24)),
+        ],
+    )
+}
+
+#[test]
+fn test_exception_groups() -> Result<()> {
+    assert_diagnostics(
+        "exception_groups.py",
+        None,
+        vec![
+            (
+                "Raises undocumented error ExceptionGroup[ValueError]",
+                (14, 16),
+                (14, 35),
+            ),
+            (
+                "Raises undocumented error ExceptionGroup[ValueError]",
+                (39, 15),
+                (39, 30),
+            ),
+            (
+                "Raises undocumented error ExceptionGroup[ValueError]",
+                (48, 9),
+                (48, 14),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_caught_types() -> Result<()> {
+    assert_diagnostics(
+        "caught_types.py",
+        None,
+        vec![("Raises undocumented error Error", (48, 9), (48, 23))],
+    )
+}
+
+#[test]
+fn test_reachability() -> Result<()> {
+    assert_diagnostics(
+        "reachability.py",
+        None,
+        vec![
+            ("Raises undocumented error RuntimeError", (30, 5), (30, 24)),
+            ("Raises undocumented error KeyError", (36, 9), (36, 25)),
+            ("Raises undocumented error KeyError", (45, 9), (45, 25)),
+            ("Raises undocumented error IndexError", (54, 9), (54, 27)),
+            ("Raises undocumented error LookupError", (60, 9), (60, 28)),
+            ("Raises undocumented error TypeError", (65, 9), (65, 26)),
+            ("Raises undocumented error ValueError", (70, 9), (70, 27)),
+        ],
+    )
+}
+
+#[test]
+fn test_reraise() -> Result<()> {
+    assert_diagnostics(
+        "reraise.py",
+        None,
+        vec![("Raises undocumented error KeyError", (67, 40), (67, 53))],
+    )
+}
+
+#[test]
+fn test_reraise_loses_context() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "reraise.py",
+        None,
+        Settings {
+            reraise_loses_context: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Raises a new exception in an except clause without `from`",
+                (19, 9),
+                (19, 34),
+            ),
+            ("Raises undocumented error KeyError", (67, 40), (67, 53)),
+        ],
+    )
+}
+
+#[test]
+fn test_narrowing() -> Result<()> {
+    assert_diagnostics(
+        "narrowing.py",
+        None,
+        vec![
+            ("Raises undocumented error Conflict", (33, 13), (33, 18)),
+            ("Raises undocumented error Timeout", (33, 13), (33, 18)),
+            ("Raises undocumented error Timeout", (42, 13), (42, 20)),
+            ("Raises undocumented error KeyError", (52, 17), (52, 22)),
+            ("Raises undocumented error KeyError", (61, 9), (61, 14)),
+            ("Raises undocumented error Conflict", (61, 9), (61, 14)),
+            ("Raises undocumented error Timeout", (61, 9), (61, 14)),
+            ("Raises undocumented error Timeout", (83, 13), (83, 18)),
+            ("Raises undocumented error Timeout", (92, 13), (92, 18)),
+        ],
+    )
+}
+
+#[test]
+fn test_handler_order() -> Result<()> {
+    assert_diagnostics(
+        "handler_order.py",
+        None,
+        vec![
+            ("Raises undocumented error KeyError", (32, 9), (32, 14)),
+            ("Raises undocumented error NotFound", (41, 9), (41, 14)),
+            ("Raises undocumented error KeyError", (50, 9), (50, 14)),
+        ],
+    )
+}
+
+#[test]
+fn test_unreachable_except_handlers() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "handler_order.py",
+        None,
+        Settings {
+            unreachable_except_handlers: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Except clause is unreachable, an earlier clause already catches Exception",
+                (22, 12),
+                (22, 20),
+            ),
+            ("Raises undocumented error KeyError", (32, 9), (32, 14)),
+            ("Raises undocumented error NotFound", (41, 9), (41, 14)),
+            ("Raises undocumented error KeyError", (50, 9), (50, 14)),
+        ],
+    )
+}
+
+#[test]
+fn test_dead_except_handlers() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "dead_handlers.py",
+        None,
+        Settings {
+            dead_except_handlers: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Except clause catches NetworkError, which the try body never raises",
+                (43, 12),
+                (43, 24),
+            ),
+            (
+                "Except clause catches ParseError, NetworkError, which the try body never raises",
+                (74, 12),
+                (74, 38),
+            ),
+            (
+                "Except clause catches NetworkError, which the try body never raises",
+                (111, 12),
+                (111, 24),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_dead_except_handlers_stdlib() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "dead_handlers.py",
+        None,
+        Settings {
+            dead_except_handlers: true,
+            stdlib_exceptions: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Except clause catches NetworkError, which the try body never raises",
+                (43, 12),
+                (43, 24),
+            ),
+            (
+                "Except clause catches ParseError, NetworkError, which the try body never raises",
+                (74, 12),
+                (74, 38),
+            ),
+            (
+                "Except clause catches NetworkError, which the try body never raises",
+                (111, 12),
+                (111, 24),
+            ),
+        ],
+    )
+}
+
+#[test]
+fn test_dead_except_handlers_target() -> Result<()> {
+    assert_diagnostics_with_settings(
+        "dead_handlers.py",
+        Some("dead_handlers.NetworkError".into()),
+        Settings {
+            dead_except_handlers: true,
+            ..Settings::default()
+        },
+        vec![
+            (
+                "Except clause catches NetworkError, which the try body never raises",
+                (43, 12),
+                (43, 24),
+            ),
+            (
+                "Except clause catches NetworkError, which the try body never raises",
+                (111, 12),
+                (111, 24),
+            ),
+        ],
+    )
+}
+
 fn assert_diagnostics(
     test_file: &str,
     target_exception: Option<String>,
     expected_diagnostics: Vec<(&str, (usize, usize), (usize, usize))>,
 ) -> Result<()> {
-    let project_path = SystemPathBuf::from_path_buf(current_dir()?)
-        .unwrap()
-        .join("tests/fixtures");
-    let filter_path = project_path.join(test_file);
-    let system = OsSystem::new(&project_path);
-    let mut project_metadata =
-        ProjectMetadata::discover(&SystemPath::new(project_path.as_str()), &system)?;
-    project_metadata.apply_configuration_files(&system)?;
-    let mut db = ProjectDatabase::new(project_metadata, system.clone())?;
-    db.project().set_included_paths(&mut db, vec![filter_path]);
+    assert_diagnostics_with_settings(
+        test_file,
+        target_exception,
+        Settings::default(),
+        expected_diagnostics,
+    )
+}
+
+fn assert_diagnostics_with_settings(
+    test_file: &str,
+    target_exception: Option<String>,
+    settings: Settings,
+    expected_diagnostics: Vec<(&str, (usize, usize), (usize, usize))>,
+) -> Result<()> {
+    let project_path = fixtures_path()?;
+    let db = fixture_db(test_file)?;
     let db2 = db.clone();
     let project_path2 = project_path.clone();
     let target_exceptions = target_exception
         .iter()
         .map(|e| resolve_absolute_module_path(&db, e))
         .collect::<Vec<_>>();
-    let diagnostics: Vec<Diagnostic> = analyze_project(db, target_exceptions, None)?.collect();
+    let diagnostics: Vec<Diagnostic> =
+        analyze_project(db, target_exceptions, settings, None)?.collect();
 
     let expected_file = File::new(&db2, FilePath::System(project_path2.join(test_file)));
     let source = source_text(&db2, expected_file);
@@ -194,3 +1119,22 @@ fn assert_diagnostics(
     }
     Ok(())
 }
+
+fn fixtures_path() -> Result<SystemPathBuf> {
+    Ok(SystemPathBuf::from_path_buf(current_dir()?)
+        .unwrap()
+        .join("tests/fixtures"))
+}
+
+/// Creates a database for the fixtures project that only checks `test_file`.
+fn fixture_db(test_file: &str) -> Result<ProjectDatabase> {
+    let project_path = fixtures_path()?;
+    let filter_path = project_path.join(test_file);
+    let system = OsSystem::new(&project_path);
+    let mut project_metadata =
+        ProjectMetadata::discover(&SystemPath::new(project_path.as_str()), &system)?;
+    project_metadata.apply_configuration_files(&system)?;
+    let mut db = ProjectDatabase::new(project_metadata, system)?;
+    db.project().set_included_paths(&mut db, vec![filter_path]);
+    Ok(db)
+}